@@ -0,0 +1,23 @@
+use std::hint::black_box;
+
+use array_vector_space::{ArrayVectorSpace, ArrayVectorSpaceFloat};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+/// Compare with and without `RUSTFLAGS="-C target-cpu=native"`: without hardware FMA, `mul_add`
+/// falls back to a software routine.
+fn fma(c: &mut Criterion) {
+    let x = [[1.5_f64; 8]; 2048];
+    let a = [[0.75_f64; 8]; 2048];
+    let y = [[0.25_f64; 8]; 2048];
+    let mut group = c.benchmark_group("fma [[f64; 8]; 2048]");
+    group.bench_function("mul.add", |b| {
+        b.iter(|| black_box(x).mul(black_box(a)).add(black_box(y)))
+    });
+    group.bench_function("fma", |b| {
+        b.iter(|| black_box(x).fma(black_box(a), black_box(y)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, fma);
+criterion_main!(benches);
@@ -0,0 +1,21 @@
+use std::hint::black_box;
+
+use array_vector_space::{ArrayVectorSpace, ArrayVectorSpaceFloat};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+fn pairwise(c: &mut Criterion) {
+    let x = Box::new([0.1_f64; 1 << 16]);
+    let y = Box::new([1.0_f64; 1 << 16]);
+    let mut group = c.benchmark_group("dot 65536");
+    group.bench_function("dot", |b| b.iter(|| black_box(*x).dot(black_box(*y))));
+    group.bench_function("dot_pairwise", |b| {
+        b.iter(|| black_box(*x).dot_pairwise(black_box(*y)))
+    });
+    group.bench_function("dot_compensated", |b| {
+        b.iter(|| black_box(*x).dot_compensated(black_box(*y)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, pairwise);
+criterion_main!(benches);
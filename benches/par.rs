@@ -0,0 +1,29 @@
+use std::hint::black_box;
+
+use array_vector_space::{ArrayVectorSpace, ArrayVectorSpaceMut, ArrayVectorSpacePar};
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+
+fn par(c: &mut Criterion) {
+    let x = vec![[0.1_f64; 8]; 262144];
+    let y = vec![[1.0_f64; 8]; 262144];
+    let mut group = c.benchmark_group("[[f64; 8]; 262144]");
+    group.bench_function("dot", |b| {
+        b.iter_batched(
+            || (x.clone(), y.clone()),
+            |(x, y)| black_box(x).dot(y),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("par_dot", |b| b.iter(|| black_box(&x[..]).par_dot(&y)));
+    let mut z = x.clone();
+    group.bench_function("mut_add", |b| {
+        b.iter(|| black_box(&mut z[..]).mut_add(&y[..]))
+    });
+    group.bench_function("mut_par_add", |b| {
+        b.iter(|| black_box(&mut z[..]).mut_par_add(&y))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, par);
+criterion_main!(benches);
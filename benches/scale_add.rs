@@ -0,0 +1,25 @@
+use std::hint::black_box;
+
+use array_vector_space::{ArrayVectorSpace, ArrayVectorSpaceFloat, ArrayVectorSpaceFloatMut};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+/// The fused forms only win with hardware FMA, e.g. `RUSTFLAGS="-C target-cpu=native"` on x86-64;
+/// otherwise `mul_add` is a software routine.
+fn scale_add(c: &mut Criterion) {
+    let x = [[0.5_f64; 5]; 1024];
+    let mut y = [[1.0_f64; 5]; 1024];
+    let mut group = c.benchmark_group("axpy [[f64; 5]; 1024]");
+    group.bench_function("add(scal_mul)", |b| {
+        b.iter(|| y = black_box(y).add(black_box(x).scal_mul(1e-3)))
+    });
+    group.bench_function("scale_add", |b| {
+        b.iter(|| y = black_box(y).scale_add(black_box(x), 1e-3))
+    });
+    group.bench_function("mut_scale_add", |b| {
+        b.iter(|| black_box(&mut y).mut_scale_add(black_box(&x), 1e-3))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, scale_add);
+criterion_main!(benches);
@@ -0,0 +1,29 @@
+use std::hint::black_box;
+
+use array_vector_space::{ArrayVectorSpace, ArrayVectorSpaceMut};
+use criterion::{Criterion, criterion_group, criterion_main};
+
+/// Run once as is and once with `--features simd` to compare the scalar and lane-wise kernels.
+fn sizes<const N: usize>(c: &mut Criterion) {
+    let x = [1.0_f32; N];
+    let mut y = [0.5_f32; N];
+    let mut group = c.benchmark_group(format!("[f32; {N}]"));
+    group.bench_function("dot", |b| b.iter(|| black_box(x).dot(black_box(y))));
+    group.bench_function("add", |b| b.iter(|| black_box(x).add(black_box(y))));
+    group.bench_function("mut_mul", |b| {
+        b.iter(|| black_box(&mut y).mut_mul(black_box(&x)))
+    });
+    group.bench_function("mut_scal_mul", |b| {
+        b.iter(|| black_box(&mut y).mut_scal_mul(black_box(1.0)))
+    });
+    group.finish();
+}
+
+fn simd(c: &mut Criterion) {
+    sizes::<3>(c);
+    sizes::<64>(c);
+    sizes::<4096>(c);
+}
+
+criterion_group!(benches, simd);
+criterion_main!(benches);
@@ -0,0 +1,32 @@
+//! Builds the crate without `std`, as firmware would use it:
+//! `cargo build -p no_std_check --target thumbv7em-none-eabihf`.
+
+#![no_std]
+
+use array_vector_space::{
+    ArrayVectorSpace, ArrayVectorSpaceFloat, ArrayVectorSpaceFloatMut, ArrayVectorSpaceInit,
+};
+
+pub fn step(x: &mut [[f32; 3]; 4], v: &[[f32; 3]; 4], dt: f32) -> f32 {
+    x.mut_scale_add(v, dt);
+    x.mut_clamp_length_max(100.0);
+    x.norm()
+}
+
+pub fn direction(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    b.sub(a)
+        .try_normalized()
+        .unwrap_or_else(|| <[f64; 3]>::unit(0))
+}
+
+pub fn mean_abs(x: [i32; 8]) -> i32 {
+    x.map_scalar(i32::abs).mean()
+}
+
+pub fn residual(x: [[f64; 4]; 4], p: f64) -> (f64, f64, f64) {
+    (x.norm_inf(), x.norm_l1(), x.norm_p(p))
+}
+
+pub fn heading(a: [f32; 2], b: [f32; 2]) -> f32 {
+    a.angle_between(b)
+}
@@ -0,0 +1,402 @@
+//! Heap-backed `Box<[V; N]>`, so that large arrays never land on the stack. The by-value
+//! operations reuse the box's allocation through the `Vec` implementations, and the in-place ones
+//! go through the slice implementations.
+
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::ops::Neg;
+
+use crate::vec::{mut_normalized, mut_try_normalized, norm2};
+use crate::{
+    ArrayVectorSpace, ArrayVectorSpaceFloat, ArrayVectorSpaceFloatMut, ArrayVectorSpaceInit,
+    ArrayVectorSpaceInteger, ArrayVectorSpaceMut, ArrayVectorSpacePredicates, Float, Integer,
+    Scalar,
+};
+
+fn to_vec<V, const N: usize>(b: Box<[V; N]>) -> Vec<V> {
+    (b as Box<[V]>).into_vec()
+}
+
+fn from_vec<V, const N: usize>(v: Vec<V>) -> Box<[V; N]> {
+    v.into_boxed_slice()
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("length is preserved"))
+}
+
+impl<S: Scalar, const N: usize, V: ArrayVectorSpaceInit<S> + Copy> ArrayVectorSpaceInit<S>
+    for Box<[V; N]>
+{
+    const DIM: usize = N * V::DIM;
+    fn zero() -> Self {
+        from_vec(vec![V::zero(); N])
+    }
+    fn one() -> Self {
+        from_vec(vec![V::one(); N])
+    }
+    fn splat(value: S) -> Self {
+        from_vec(vec![V::splat(value); N])
+    }
+    fn from_fn<F: FnMut(usize) -> S>(mut f: F) -> Self {
+        from_vec((0..N).map(|i| V::from_fn(|j| f(i * V::DIM + j))).collect())
+    }
+}
+
+impl<S: Scalar, const N: usize, V: ArrayVectorSpaceInit<S> + Copy> ArrayVectorSpace<S>
+    for Box<[V; N]>
+{
+    fn dot(self, rhs: Self) -> S {
+        to_vec(self).dot(to_vec(rhs))
+    }
+    fn sum(self) -> S {
+        to_vec(self).sum()
+    }
+    fn product(self) -> S {
+        to_vec(self).product()
+    }
+    fn mean(self) -> S {
+        to_vec(self).mean()
+    }
+    fn norm2(self) -> S
+    where
+        Self: Sized + Clone,
+    {
+        norm2(&self[..])
+    }
+    fn add(self, rhs: Self) -> Self {
+        from_vec(to_vec(self).add(to_vec(rhs)))
+    }
+    fn sub(self, rhs: Self) -> Self {
+        from_vec(to_vec(self).sub(to_vec(rhs)))
+    }
+    fn mul(self, rhs: Self) -> Self {
+        from_vec(to_vec(self).mul(to_vec(rhs)))
+    }
+    fn div(self, rhs: Self) -> Self {
+        from_vec(to_vec(self).div(to_vec(rhs)))
+    }
+    fn neg(self) -> Self
+    where
+        S: Neg<Output = S>,
+    {
+        from_vec(to_vec(self).neg())
+    }
+    fn scal_mul(self, rhs: S) -> Self {
+        from_vec(to_vec(self).scal_mul(rhs))
+    }
+    fn scal_div(self, rhs: S) -> Self {
+        from_vec(to_vec(self).scal_div(rhs))
+    }
+    fn scal_add(self, rhs: S) -> Self {
+        from_vec(to_vec(self).scal_add(rhs))
+    }
+    fn scal_sub(self, rhs: S) -> Self {
+        from_vec(to_vec(self).scal_sub(rhs))
+    }
+    fn clamp(self, min: S, max: S) -> Self {
+        from_vec(to_vec(self).clamp(min, max))
+    }
+    fn clamp_vec(self, min: Self, max: Self) -> Self {
+        from_vec(to_vec(self).clamp_vec(to_vec(min), to_vec(max)))
+    }
+    fn signum(self) -> Self {
+        from_vec(to_vec(self).signum())
+    }
+    fn map_scalar<F: FnMut(S) -> S>(self, f: F) -> Self {
+        from_vec(to_vec(self).map_scalar(f))
+    }
+    fn zip_with_scalar<F: FnMut(S, S) -> S>(self, rhs: Self, f: F) -> Self {
+        from_vec(to_vec(self).zip_with_scalar(to_vec(rhs), f))
+    }
+    fn fold_scalar<A, F: FnMut(A, S) -> A>(self, init: A, f: F) -> A {
+        to_vec(self).fold_scalar(init, f)
+    }
+    fn min_comp(self, rhs: Self) -> Self {
+        from_vec(to_vec(self).min_comp(to_vec(rhs)))
+    }
+    fn max_comp(self, rhs: Self) -> Self {
+        from_vec(to_vec(self).max_comp(to_vec(rhs)))
+    }
+}
+
+impl<S: Float, const N: usize, V: ArrayVectorSpaceFloat<S> + ArrayVectorSpaceInit<S> + Copy>
+    ArrayVectorSpaceFloat<S> for Box<[V; N]>
+{
+    fn norm(self) -> S {
+        to_vec(self).norm()
+    }
+    fn dot_compensated(self, rhs: Self) -> S {
+        to_vec(self).dot_compensated(to_vec(rhs))
+    }
+    fn dot_compensated_acc(self, rhs: Self, acc: (S, S)) -> (S, S) {
+        to_vec(self).dot_compensated_acc(to_vec(rhs), acc)
+    }
+    fn dot_pairwise_with(self, rhs: Self, threshold: usize) -> S {
+        to_vec(self).dot_pairwise_with(to_vec(rhs), threshold)
+    }
+    fn norm_l1(self) -> S {
+        to_vec(self).norm_l1()
+    }
+    fn norm_inf(self) -> S {
+        to_vec(self).norm_inf()
+    }
+    fn min_element(self) -> S {
+        to_vec(self).min_element()
+    }
+    fn max_element(self) -> S {
+        to_vec(self).max_element()
+    }
+    fn norm_p(self, p: S) -> S {
+        to_vec(self).norm_p(p)
+    }
+    fn norm_p_sum(self, p: S) -> S {
+        to_vec(self).norm_p_sum(p)
+    }
+    fn abs(self) -> Self {
+        from_vec(to_vec(self).abs())
+    }
+    fn recip(self) -> Self {
+        from_vec(to_vec(self).recip())
+    }
+    fn floor(self) -> Self {
+        from_vec(to_vec(self).floor())
+    }
+    fn ceil(self) -> Self {
+        from_vec(to_vec(self).ceil())
+    }
+    fn round(self) -> Self {
+        from_vec(to_vec(self).round())
+    }
+    fn round_ties_even(self) -> Self {
+        from_vec(to_vec(self).round_ties_even())
+    }
+    fn sqrt(self) -> Self {
+        from_vec(to_vec(self).sqrt())
+    }
+    fn exp(self) -> Self {
+        from_vec(to_vec(self).exp())
+    }
+    fn ln(self) -> Self {
+        from_vec(to_vec(self).ln())
+    }
+    fn exp2(self) -> Self {
+        from_vec(to_vec(self).exp2())
+    }
+    fn log2(self) -> Self {
+        from_vec(to_vec(self).log2())
+    }
+    fn sin(self) -> Self {
+        from_vec(to_vec(self).sin())
+    }
+    fn cos(self) -> Self {
+        from_vec(to_vec(self).cos())
+    }
+    fn tan(self) -> Self {
+        from_vec(to_vec(self).tan())
+    }
+    fn asin(self) -> Self {
+        from_vec(to_vec(self).asin())
+    }
+    fn acos(self) -> Self {
+        from_vec(to_vec(self).acos())
+    }
+    fn atan(self) -> Self {
+        from_vec(to_vec(self).atan())
+    }
+    fn atan2(self, rhs: Self) -> Self {
+        from_vec(to_vec(self).atan2(to_vec(rhs)))
+    }
+    fn powi(self, n: i32) -> Self {
+        from_vec(to_vec(self).powi(n))
+    }
+    fn powf(self, rhs: Self) -> Self {
+        from_vec(to_vec(self).powf(to_vec(rhs)))
+    }
+    fn powf_scalar(self, exp: S) -> Self {
+        from_vec(to_vec(self).powf_scalar(exp))
+    }
+    fn lerp(self, rhs: Self, t: S) -> Self {
+        from_vec(to_vec(self).lerp(to_vec(rhs), t))
+    }
+    fn scale_add(self, rhs: Self, factor: S) -> Self {
+        from_vec(to_vec(self).scale_add(to_vec(rhs), factor))
+    }
+    fn fma(self, a: Self, b: Self) -> Self {
+        from_vec(to_vec(self).fma(to_vec(a), to_vec(b)))
+    }
+    fn cosine_similarity(self, rhs: Self) -> S {
+        to_vec(self).cosine_similarity(to_vec(rhs))
+    }
+    fn angle_between(self, rhs: Self) -> S {
+        to_vec(self).angle_between(to_vec(rhs))
+    }
+    fn refract(self, normal: Self, eta: S) -> Option<Self> {
+        to_vec(self).refract(to_vec(normal), eta).map(from_vec)
+    }
+    fn normalized(self) -> Self {
+        from_vec(to_vec(self).normalized())
+    }
+    fn try_normalized(self) -> Option<Self> {
+        to_vec(self).try_normalized().map(from_vec)
+    }
+}
+
+impl<S: Scalar, const N: usize, V: ArrayVectorSpaceMut<S> + Copy> ArrayVectorSpaceMut<S>
+    for Box<[V; N]>
+{
+    fn mut_add(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_add(rhs.as_slice())
+    }
+    fn mut_sub(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_sub(rhs.as_slice())
+    }
+    fn mut_mul(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_mul(rhs.as_slice())
+    }
+    fn mut_div(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_div(rhs.as_slice())
+    }
+    fn mut_neg(&mut self)
+    where
+        S: Neg<Output = S>,
+    {
+        self.as_mut_slice().mut_neg()
+    }
+    fn mut_scal_mul(&mut self, rhs: S) {
+        self.as_mut_slice().mut_scal_mul(rhs)
+    }
+    fn mut_scal_div(&mut self, rhs: S) {
+        self.as_mut_slice().mut_scal_div(rhs)
+    }
+    fn mut_scal_add(&mut self, rhs: S) {
+        self.as_mut_slice().mut_scal_add(rhs)
+    }
+    fn mut_scal_sub(&mut self, rhs: S) {
+        self.as_mut_slice().mut_scal_sub(rhs)
+    }
+    fn mut_clamp(&mut self, min: S, max: S) {
+        self.as_mut_slice().mut_clamp(min, max)
+    }
+    fn mut_clamp_vec(&mut self, min: &Self, max: &Self) {
+        self.as_mut_slice()
+            .mut_clamp_vec(min.as_slice(), max.as_slice())
+    }
+    fn mut_signum(&mut self) {
+        self.as_mut_slice().mut_signum()
+    }
+    fn mut_min_comp(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_min_comp(rhs.as_slice())
+    }
+    fn mut_max_comp(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_max_comp(rhs.as_slice())
+    }
+}
+
+impl<S: Float, const N: usize, V: ArrayVectorSpaceFloatMut<S> + ArrayVectorSpace<S> + Copy>
+    ArrayVectorSpaceFloatMut<S> for Box<[V; N]>
+{
+    fn mut_abs(&mut self) {
+        self.as_mut_slice().mut_abs()
+    }
+    fn mut_recip(&mut self) {
+        self.as_mut_slice().mut_recip()
+    }
+    fn mut_floor(&mut self) {
+        self.as_mut_slice().mut_floor()
+    }
+    fn mut_ceil(&mut self) {
+        self.as_mut_slice().mut_ceil()
+    }
+    fn mut_round(&mut self) {
+        self.as_mut_slice().mut_round()
+    }
+    fn mut_round_ties_even(&mut self) {
+        self.as_mut_slice().mut_round_ties_even()
+    }
+    fn mut_sqrt(&mut self) {
+        self.as_mut_slice().mut_sqrt()
+    }
+    fn mut_exp(&mut self) {
+        self.as_mut_slice().mut_exp()
+    }
+    fn mut_ln(&mut self) {
+        self.as_mut_slice().mut_ln()
+    }
+    fn mut_exp2(&mut self) {
+        self.as_mut_slice().mut_exp2()
+    }
+    fn mut_log2(&mut self) {
+        self.as_mut_slice().mut_log2()
+    }
+    fn mut_sin(&mut self) {
+        self.as_mut_slice().mut_sin()
+    }
+    fn mut_cos(&mut self) {
+        self.as_mut_slice().mut_cos()
+    }
+    fn mut_tan(&mut self) {
+        self.as_mut_slice().mut_tan()
+    }
+    fn mut_asin(&mut self) {
+        self.as_mut_slice().mut_asin()
+    }
+    fn mut_acos(&mut self) {
+        self.as_mut_slice().mut_acos()
+    }
+    fn mut_atan(&mut self) {
+        self.as_mut_slice().mut_atan()
+    }
+    fn mut_atan2(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_atan2(rhs.as_slice())
+    }
+    fn mut_powi(&mut self, n: i32) {
+        self.as_mut_slice().mut_powi(n)
+    }
+    fn mut_powf(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_powf(rhs.as_slice())
+    }
+    fn mut_powf_scalar(&mut self, exp: S) {
+        self.as_mut_slice().mut_powf_scalar(exp)
+    }
+    fn mut_lerp(&mut self, rhs: &Self, t: S) {
+        self.as_mut_slice().mut_lerp(rhs.as_slice(), t)
+    }
+    fn mut_scale_add(&mut self, rhs: &Self, factor: S) {
+        self.as_mut_slice().mut_scale_add(rhs.as_slice(), factor)
+    }
+    fn mut_fma(&mut self, a: &Self, b: &Self) {
+        self.as_mut_slice().mut_fma(a.as_slice(), b.as_slice())
+    }
+    fn mut_normalized(&mut self)
+    where
+        Self: ArrayVectorSpaceFloat<S>,
+    {
+        mut_normalized(self.as_mut_slice())
+    }
+    fn mut_try_normalized(&mut self) -> bool
+    where
+        Self: ArrayVectorSpaceFloat<S>,
+    {
+        mut_try_normalized(self.as_mut_slice())
+    }
+}
+
+impl<S: Integer, const N: usize, V: ArrayVectorSpaceInteger<S> + ArrayVectorSpaceInit<S> + Copy>
+    ArrayVectorSpaceInteger<S> for Box<[V; N]>
+{
+    fn checked_dot(self, rhs: Self) -> Option<S> {
+        to_vec(self).checked_dot(to_vec(rhs))
+    }
+    fn normalized(self) -> Self {
+        from_vec(to_vec(self).normalized())
+    }
+}
+
+impl<S: Scalar, const N: usize, V: ArrayVectorSpacePredicates<S>> ArrayVectorSpacePredicates<S>
+    for Box<[V; N]>
+{
+    fn all<F: FnMut(S) -> bool>(self, f: F) -> bool {
+        to_vec(self).all(f)
+    }
+    fn any<F: FnMut(S) -> bool>(self, f: F) -> bool {
+        to_vec(self).any(f)
+    }
+}
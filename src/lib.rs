@@ -1,172 +1,2958 @@
+//! Vector-space operations on plain arrays, nested to any depth, with scalars at the leaves.
+//!
+//! # Features
+//!
+//! - `std` (default): enables `alloc` and takes the float functions from `std`.
+//! - `alloc`: implementations for `Vec<V>` and `Box<[V; N]>`.
+//! - `libm`: takes the float functions from `libm` when `std` is off, for `no_std` targets.
+//! - `rayon`, `serde`, `simd`: parallel slice operations, `Vector` serialization and vectorized
+//!   kernels for the innermost `f32`/`f64` arrays.
+//!
+//! Without `std` or `libm` there is no `sqrt`, so `f32` and `f64` only get `ArrayVectorSpace`
+//! and the other traits that need no `Float`; `ArrayVectorSpaceFloat` and its relatives are
+//! missing rather than failing to build. A `no_std` firmware build looks like
+//!
+//! ```toml
+//! array_vector_space = { version = "0.1", default-features = false, features = ["libm"] }
+//! ```
+//!
+//! and `no_std_check/` is built for `thumbv7em-none-eabihf` to keep that configuration working.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+mod boxed;
+#[cfg(feature = "rayon")]
+mod par;
+mod scalar;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "alloc")]
+mod vec;
+mod vector;
+
+#[cfg(feature = "rayon")]
+pub use par::{ArrayVectorSpaceFloatPar, ArrayVectorSpacePar, PAR_MIN_LEAVES};
+pub use scalar::{Float, Integer, Scalar};
+pub use vector::Vector;
+
+use core::ops::Neg;
+
 pub trait ArrayVectorSpace<T> {
     fn dot(self, rhs: Self) -> T;
+    fn sum(self) -> T;
+    fn product(self) -> T;
+    /// `sum` divided by `DIM`, so nested arrays average over every leaf.
+    fn mean(self) -> T;
+    /// Integer leaves overflow like `*` and `+` do, `checked_norm2` reports it instead.
     fn norm2(self) -> T
     where
-        Self: Sized + Copy,
+        Self: Sized + Clone,
+    {
+        self.clone().dot(self)
+    }
+    fn distance2(self, rhs: Self) -> T
+    where
+        Self: Sized + Clone,
+    {
+        self.sub(rhs).norm2()
+    }
+    /// `(self + rhs) / 2` per leaf through `Scalar::midpoint`, so the sum never overflows.
+    /// Signed integers round towards zero and unsigned ones down.
+    fn midpoint(self, rhs: Self) -> Self
+    where
+        Self: Sized,
+        T: Scalar,
     {
-        self.dot(self)
+        self.zip_with_scalar(rhs, T::midpoint)
     }
     fn add(self, rhs: Self) -> Self;
     fn sub(self, rhs: Self) -> Self;
     fn mul(self, rhs: Self) -> Self;
     fn div(self, rhs: Self) -> Self;
+    /// Negates every leaf; unsigned integers have no negation.
+    fn neg(self) -> Self
+    where
+        T: Neg<Output = T>;
     fn scal_mul(self, rhs: T) -> Self;
+    /// Divides every leaf by `rhs`, rather than multiplying by its reciprocal.
+    fn scal_div(self, rhs: T) -> Self;
+    fn scal_add(self, rhs: T) -> Self;
+    fn scal_sub(self, rhs: T) -> Self;
+    /// Integer arrays are also `Ord`, so call this as `ArrayVectorSpace::clamp(v, min, max)` or
+    /// through `clamp_scalar`.
     fn clamp(self, min: T, max: T) -> Self;
-    fn normalized(self) -> Self;
+    /// Same as `clamp`, under a name that does not collide with `Ord::clamp` on integer arrays.
+    fn clamp_scalar(self, min: T, max: T) -> Self
+    where
+        Self: Sized,
+    {
+        ArrayVectorSpace::clamp(self, min, max)
+    }
+    /// Clamps each leaf between the matching leaves of `min` and `max`. Panics like the scalar
+    /// `clamp` if a `min` leaf is greater than its `max` leaf.
+    fn clamp_vec(self, min: Self, max: Self) -> Self;
+    /// Per-leaf sign: `-1`, `0` or `1` for integers, and `f32::signum` semantics for floats, where
+    /// a zero gives `±1` and NaN stays NaN.
+    fn signum(self) -> Self;
+    /// Applies `f` to every leaf, in row-major order. Unlike the inherent `<[V; N]>::map`, this
+    /// reaches through nested arrays down to the scalars.
+    fn map_scalar<F: FnMut(T) -> T>(self, f: F) -> Self;
+    /// Combines matching leaves of `self` and `rhs` with `f`, in row-major order.
+    fn zip_with_scalar<F: FnMut(T, T) -> T>(self, rhs: Self, f: F) -> Self;
+    /// Folds every leaf into `init`, in row-major order.
+    fn fold_scalar<A, F: FnMut(A, T) -> A>(self, init: A, f: F) -> A;
+    /// Calls `f` on every leaf, in row-major order.
+    fn for_each_scalar<F: FnMut(T)>(self, mut f: F)
+    where
+        Self: Sized,
+    {
+        self.fold_scalar((), |(), x| f(x))
+    }
+    /// Per-leaf `min`; for floats a NaN leaf yields the other operand, as with `f32::min`.
+    fn min_comp(self, rhs: Self) -> Self;
+    fn max_comp(self, rhs: Self) -> Self;
+    /// Per-element kernels used by the array and slice impls, so that scalar leaves can take
+    /// the `simd` path.
+    #[doc(hidden)]
+    fn dot_slice(x: &[Self], y: &[Self]) -> T
+    where
+        Self: Sized + Copy,
+        T: Scalar,
+    {
+        x.iter()
+            .zip(y)
+            .map(|(v, w)| v.dot(*w))
+            .fold(T::ZERO, |a, b| a + b)
+    }
+    #[doc(hidden)]
+    fn add_slice(x: &mut [Self], y: &[Self])
+    where
+        Self: Sized + Copy,
+    {
+        x.iter_mut().zip(y).for_each(|(v, w)| *v = v.add(*w));
+    }
+    #[doc(hidden)]
+    fn mul_slice(x: &mut [Self], y: &[Self])
+    where
+        Self: Sized + Copy,
+    {
+        x.iter_mut().zip(y).for_each(|(v, w)| *v = v.mul(*w));
+    }
+    #[doc(hidden)]
+    fn scal_mul_slice(x: &mut [Self], rhs: T)
+    where
+        Self: Sized + Copy,
+        T: Scalar,
+    {
+        x.iter_mut().for_each(|v| *v = v.scal_mul(rhs));
+    }
 }
 
-macro_rules! impl_vector_space {
-    ($t: ty) => {
-        impl ArrayVectorSpace<$t> for $t {
-            fn dot(self, rhs: Self) -> $t {
-                self * rhs
-            }
-            fn add(self, rhs: Self) -> Self {
-                self + rhs
-            }
-            fn sub(self, rhs: Self) -> Self {
-                self - rhs
-            }
-            fn mul(self, rhs: Self) -> Self {
-                self * rhs
-            }
-            fn div(self, rhs: Self) -> Self {
-                self / rhs
-            }
-            fn scal_mul(self, rhs: $t) -> Self {
-                self * rhs
-            }
-            fn clamp(self, min: $t, max: $t) -> Self {
-                self.clamp(min, max)
-            }
-            fn normalized(self) -> Self {
-                1.0
-            }
-        }
-        impl<const N: usize, V: ArrayVectorSpace<$t> + Copy> ArrayVectorSpace<$t> for [V; N] {
-            fn dot(self, rhs: Self) -> $t {
-                self.into_iter()
-                    .zip(rhs.into_iter())
-                    .map(|(v, w)| v.dot(w))
-                    .fold(0.0, <$t>::add)
-            }
-            fn add(mut self, rhs: Self) -> Self {
-                self.iter_mut()
-                    .zip(rhs.into_iter())
-                    .for_each(|(v, w)| *v = v.add(w));
-                self
-            }
-            fn sub(mut self, rhs: Self) -> Self {
-                self.iter_mut()
-                    .zip(rhs.into_iter())
-                    .for_each(|(v, w)| *v = v.sub(w));
-                self
-            }
-            fn mul(mut self, rhs: Self) -> Self {
-                self.iter_mut()
-                    .zip(rhs.into_iter())
-                    .for_each(|(v, w)| *v = v.mul(w));
-                self
-            }
-            fn div(mut self, rhs: Self) -> Self {
-                self.iter_mut()
-                    .zip(rhs.into_iter())
-                    .for_each(|(v, w)| *v = v.div(w));
-                self
-            }
-            fn scal_mul(mut self, rhs: $t) -> Self {
-                self.iter_mut().for_each(|v| *v = v.scal_mul(rhs));
-                self
-            }
-            fn clamp(mut self, min: $t, max: $t) -> Self {
-                self.iter_mut().for_each(|v| *v = v.clamp(min, max));
-                self
-            }
-            fn normalized(self) -> Self {
-                let n = self.norm2().sqrt();
-                self.scal_mul(n.recip())
-            }
-        }
-    };
+impl<S: Scalar> ArrayVectorSpace<S> for S {
+    fn dot(self, rhs: Self) -> S {
+        self * rhs
+    }
+    fn sum(self) -> S {
+        self
+    }
+    fn product(self) -> S {
+        self
+    }
+    fn mean(self) -> S {
+        self
+    }
+    fn add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    fn sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+    fn mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    fn div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+    fn neg(self) -> Self
+    where
+        S: Neg<Output = S>,
+    {
+        -self
+    }
+    fn scal_mul(self, rhs: S) -> Self {
+        self * rhs
+    }
+    fn scal_div(self, rhs: S) -> Self {
+        self / rhs
+    }
+    fn scal_add(self, rhs: S) -> Self {
+        self + rhs
+    }
+    fn scal_sub(self, rhs: S) -> Self {
+        self - rhs
+    }
+    fn clamp(self, min: S, max: S) -> Self {
+        S::clamp(self, min, max)
+    }
+    fn clamp_vec(self, min: Self, max: Self) -> Self {
+        S::clamp(self, min, max)
+    }
+    fn signum(self) -> Self {
+        S::signum(self)
+    }
+    fn map_scalar<F: FnMut(S) -> S>(self, mut f: F) -> Self {
+        f(self)
+    }
+    fn zip_with_scalar<F: FnMut(S, S) -> S>(self, rhs: Self, mut f: F) -> Self {
+        f(self, rhs)
+    }
+    fn fold_scalar<A, F: FnMut(A, S) -> A>(self, init: A, mut f: F) -> A {
+        f(init, self)
+    }
+    fn min_comp(self, rhs: Self) -> Self {
+        S::min(self, rhs)
+    }
+    fn max_comp(self, rhs: Self) -> Self {
+        S::max(self, rhs)
+    }
+    fn dot_slice(x: &[S], y: &[S]) -> S {
+        <S as Scalar>::dot_slice(x, y)
+    }
+    fn add_slice(x: &mut [S], y: &[S]) {
+        <S as Scalar>::add_slice(x, y)
+    }
+    fn mul_slice(x: &mut [S], y: &[S]) {
+        <S as Scalar>::mul_slice(x, y)
+    }
+    fn scal_mul_slice(x: &mut [S], rhs: S) {
+        <S as Scalar>::scal_mul_slice(x, rhs)
+    }
 }
 
-impl_vector_space! {f32}
-impl_vector_space! {f64}
+impl<S: Scalar, const N: usize, V: ArrayVectorSpaceInit<S> + Copy> ArrayVectorSpace<S> for [V; N] {
+    fn dot(self, rhs: Self) -> S {
+        V::dot_slice(&self, &rhs)
+    }
+    fn sum(self) -> S {
+        self.into_iter()
+            .map(|v| v.sum())
+            .fold(S::ZERO, |a, b| a + b)
+    }
+    fn product(self) -> S {
+        self.into_iter()
+            .map(|v| v.product())
+            .fold(S::ONE, |a, b| a * b)
+    }
+    fn mean(self) -> S {
+        self.sum() / S::from_usize(Self::DIM)
+    }
+    fn add(mut self, rhs: Self) -> Self {
+        V::add_slice(&mut self, &rhs);
+        self
+    }
+    fn sub(mut self, rhs: Self) -> Self {
+        self.iter_mut().zip(rhs).for_each(|(v, w)| *v = v.sub(w));
+        self
+    }
+    fn mul(mut self, rhs: Self) -> Self {
+        V::mul_slice(&mut self, &rhs);
+        self
+    }
+    fn div(mut self, rhs: Self) -> Self {
+        self.iter_mut().zip(rhs).for_each(|(v, w)| *v = v.div(w));
+        self
+    }
+    fn neg(mut self) -> Self
+    where
+        S: Neg<Output = S>,
+    {
+        self.iter_mut().for_each(|v| *v = v.neg());
+        self
+    }
+    fn scal_mul(mut self, rhs: S) -> Self {
+        V::scal_mul_slice(&mut self, rhs);
+        self
+    }
+    fn scal_div(mut self, rhs: S) -> Self {
+        self.iter_mut().for_each(|v| *v = v.scal_div(rhs));
+        self
+    }
+    fn scal_add(mut self, rhs: S) -> Self {
+        self.iter_mut().for_each(|v| *v = v.scal_add(rhs));
+        self
+    }
+    fn scal_sub(mut self, rhs: S) -> Self {
+        self.iter_mut().for_each(|v| *v = v.scal_sub(rhs));
+        self
+    }
+    fn clamp(mut self, min: S, max: S) -> Self {
+        self.iter_mut().for_each(|v| *v = v.clamp(min, max));
+        self
+    }
+    fn clamp_vec(mut self, min: Self, max: Self) -> Self {
+        self.iter_mut()
+            .zip(min.into_iter().zip(max))
+            .for_each(|(v, (a, b))| *v = v.clamp_vec(a, b));
+        self
+    }
+    fn signum(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.signum());
+        self
+    }
+    fn map_scalar<F: FnMut(S) -> S>(mut self, mut f: F) -> Self {
+        self.iter_mut().for_each(|v| *v = v.map_scalar(&mut f));
+        self
+    }
+    fn zip_with_scalar<F: FnMut(S, S) -> S>(mut self, rhs: Self, mut f: F) -> Self {
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| *v = v.zip_with_scalar(w, &mut f));
+        self
+    }
+    fn fold_scalar<A, F: FnMut(A, S) -> A>(self, init: A, mut f: F) -> A {
+        self.into_iter()
+            .fold(init, |acc, v| v.fold_scalar(acc, &mut f))
+    }
+    fn min_comp(mut self, rhs: Self) -> Self {
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| *v = v.min_comp(w));
+        self
+    }
+    fn max_comp(mut self, rhs: Self) -> Self {
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| *v = v.max_comp(w));
+        self
+    }
+}
 
-pub trait ArrayVectorSpaceMut<T> {
-    fn mut_add(&mut self, rhs: &Self);
-    fn mut_sub(&mut self, rhs: &Self);
-    fn mut_mul(&mut self, rhs: &Self);
-    fn mut_div(&mut self, rhs: &Self);
-    fn mut_scal_mul(&mut self, rhs: T);
-    fn mut_clamp(&mut self, min: T, max: T);
-    fn mut_normalized(&mut self)
+/// Constructors, for the types whose shape is known at compile time. A `Vec` has no length to
+/// build from and only implements `ArrayVectorSpace`.
+pub trait ArrayVectorSpaceInit<T>: ArrayVectorSpace<T> + Sized {
+    /// Number of scalar leaves.
+    const DIM: usize;
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn splat(value: T) -> Self;
+    /// Builds every leaf from its flat row-major index, in `0..DIM`.
+    fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self;
+    /// Standard basis vector with a one at the flat row-major index `i`. Panics if `i >= DIM`.
+    fn unit(i: usize) -> Self
     where
-        Self: ArrayVectorSpace<T>;
+        T: Scalar,
+    {
+        assert!(
+            i < Self::DIM,
+            "unit index {i} out of range for DIM {}",
+            Self::DIM
+        );
+        Self::from_fn(|j| if j == i { T::ONE } else { T::ZERO })
+    }
 }
 
-macro_rules! impl_vector_space {
-    ($t: ty) => {
-        impl ArrayVectorSpaceMut<$t> for $t {
-            fn mut_add(&mut self, rhs: &Self) {
-                *self += *rhs
-            }
-            fn mut_sub(&mut self, rhs: &Self) {
-                *self -= *rhs
-            }
-            fn mut_mul(&mut self, rhs: &Self) {
-                *self *= *rhs
-            }
-            fn mut_div(&mut self, rhs: &Self) {
-                *self /= *rhs
-            }
-            fn mut_scal_mul(&mut self, rhs: $t) {
-                *self *= rhs
-            }
-            fn mut_clamp(&mut self, min: $t, max: $t) {
-                *self = self.clamp(min, max);
+impl<S: Scalar> ArrayVectorSpaceInit<S> for S {
+    const DIM: usize = 1;
+    fn zero() -> Self {
+        S::ZERO
+    }
+    fn one() -> Self {
+        S::ONE
+    }
+    fn splat(value: S) -> Self {
+        value
+    }
+    fn from_fn<F: FnMut(usize) -> S>(mut f: F) -> Self {
+        f(0)
+    }
+}
+
+impl<S: Scalar, const N: usize, V: ArrayVectorSpaceInit<S> + Copy> ArrayVectorSpaceInit<S>
+    for [V; N]
+{
+    const DIM: usize = N * V::DIM;
+    fn zero() -> Self {
+        core::array::from_fn(|_| V::zero())
+    }
+    fn one() -> Self {
+        core::array::from_fn(|_| V::one())
+    }
+    fn splat(value: S) -> Self {
+        core::array::from_fn(|_| V::splat(value))
+    }
+    fn from_fn<F: FnMut(usize) -> S>(mut f: F) -> Self {
+        core::array::from_fn(|i| V::from_fn(|j| f(i * V::DIM + j)))
+    }
+}
+
+/// Default number of elements below which `dot_pairwise` stops splitting and sums sequentially.
+pub const PAIRWISE_THRESHOLD: usize = 128;
+
+fn pairwise_dot<S: Float, V: ArrayVectorSpaceFloat<S> + Copy>(
+    x: &[V],
+    y: &[V],
+    threshold: usize,
+) -> S {
+    if x.len() <= threshold.max(1) {
+        x.iter()
+            .zip(y)
+            .fold(S::ZERO, |a, (v, w)| a + v.dot_pairwise_with(*w, threshold))
+    } else {
+        let mid = x.len() / 2;
+        let ((xl, xr), (yl, yr)) = (x.split_at(mid), y.split_at(mid));
+        pairwise_dot(xl, yl, threshold) + pairwise_dot(xr, yr, threshold)
+    }
+}
+
+/// Operations that need a floating-point scalar (`abs`, `sqrt`, `recip`).
+pub trait ArrayVectorSpaceFloat<T>: ArrayVectorSpace<T> {
+    fn norm(self) -> T;
+    fn distance(self, rhs: Self) -> T
+    where
+        Self: Sized,
+    {
+        self.sub(rhs).norm()
+    }
+    /// `dot` using Neumaier summation over every product of the whole nested array.
+    fn dot_compensated(self, rhs: Self) -> T;
+    /// Adds the products of `self` and `rhs` to a Neumaier `(sum, compensation)` accumulator.
+    #[doc(hidden)]
+    fn dot_compensated_acc(self, rhs: Self, acc: (T, T)) -> (T, T);
+    fn norm2_compensated(self) -> T
+    where
+        Self: Sized + Clone,
+    {
+        self.clone().dot_compensated(self)
+    }
+    /// `dot` using pairwise summation at every nesting level, see `PAIRWISE_THRESHOLD`.
+    fn dot_pairwise(self, rhs: Self) -> T
+    where
+        Self: Sized,
+    {
+        self.dot_pairwise_with(rhs, PAIRWISE_THRESHOLD)
+    }
+    /// `dot_pairwise` summing sequentially below `threshold` elements at each level instead.
+    fn dot_pairwise_with(self, rhs: Self, threshold: usize) -> T;
+    fn norm2_pairwise(self) -> T
+    where
+        Self: Sized + Clone,
+    {
+        self.clone().dot_pairwise(self)
+    }
+    fn norm_l1(self) -> T;
+    /// Largest absolute component, `0` for an empty array. A NaN component makes the result NaN.
+    fn norm_inf(self) -> T;
+    /// Smallest leaf, ignoring NaN like `f32::min`; `+∞` for an empty array.
+    fn min_element(self) -> T;
+    /// Largest leaf, ignoring NaN like `f32::max`; `-∞` for an empty array.
+    fn max_element(self) -> T;
+    /// Flat row-major index of the first smallest leaf, ignoring NaN like `min_element`; `0` if
+    /// every leaf is NaN or the array is empty.
+    fn argmin(self) -> usize
+    where
+        Self: Sized,
+        T: Float,
+    {
+        let (_, i, _) = self.fold_scalar((0, 0, T::NAN), |(j, i, m), x| {
+            if x < m || (T::is_nan(m) && !T::is_nan(x)) {
+                (j + 1, j, x)
+            } else {
+                (j + 1, i, m)
             }
-            fn mut_normalized(&mut self) {
-                *self = 1.0
+        });
+        i
+    }
+    /// Flat row-major index of the first largest leaf, ignoring NaN like `max_element`; `0` if
+    /// every leaf is NaN or the array is empty.
+    fn argmax(self) -> usize
+    where
+        Self: Sized,
+        T: Float,
+    {
+        let (_, i, _) = self.fold_scalar((0, 0, T::NAN), |(j, i, m), x| {
+            if x > m || (T::is_nan(m) && !T::is_nan(x)) {
+                (j + 1, j, x)
+            } else {
+                (j + 1, i, m)
             }
+        });
+        i
+    }
+    /// `(Σ |x|^p)^(1/p)` over every leaf, scaled by `norm_inf` to avoid overflow for large `p`.
+    /// `p = ∞` returns `norm_inf`; `p <= 0` is not supported.
+    fn norm_p(self, p: T) -> T;
+    /// `Σ |x|^p` over every leaf, without the final root.
+    #[doc(hidden)]
+    fn norm_p_sum(self, p: T) -> T;
+    fn abs(self) -> Self;
+    /// Per-leaf `1 / x`; a zero leaf gives an infinity.
+    fn recip(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    /// Like `round`, but half-way leaves go to the nearest even integer.
+    fn round_ties_even(self) -> Self;
+    /// `sqrt` through `log2` follow the scalar domain rules, e.g. `sqrt(-1)` is NaN and `ln(0)`
+    /// is `-∞`.
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn exp2(self) -> Self;
+    fn log2(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    /// Per-leaf four-quadrant arctangent of `self / rhs`, like `f32::atan2`.
+    fn atan2(self, rhs: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    /// Raises each leaf to the power of the matching leaf of `rhs`.
+    fn powf(self, rhs: Self) -> Self;
+    /// Raises each leaf to the power `exp`; for `exp = 2`, `self.mul(self)` is cheaper.
+    fn powf_scalar(self, exp: T) -> Self;
+    /// Computed per leaf as `self + (rhs - self) * t` for `t < 0.5` and as
+    /// `rhs - (rhs - self) * (1 - t)` otherwise. `t = 0` and `t = 1` return the leaves of `self`
+    /// and `rhs` bit for bit, even for `-0.0` or when `rhs - self` overflows. `t` outside `[0, 1]`
+    /// extrapolates.
+    fn lerp(self, rhs: Self, t: T) -> Self;
+    /// Steps from `self` towards `target` by at most `max_delta` in Euclidean distance, returning
+    /// `target` exactly once it is within reach.
+    fn move_towards(self, target: Self, max_delta: T) -> Self
+    where
+        Self: Sized + Clone,
+        T: Scalar,
+    {
+        let d = target.clone().sub(self.clone());
+        let n = d.clone().norm();
+        if n <= max_delta || n == T::ZERO {
+            target
+        } else {
+            self.add(d.scal_mul(max_delta / n))
         }
-        impl<const N: usize, V: ArrayVectorSpaceMut<$t> + Copy> ArrayVectorSpaceMut<$t> for [V; N] {
-            fn mut_add(&mut self, rhs: &Self) {
-                self.iter_mut()
-                    .zip(rhs.into_iter())
-                    .for_each(|(v, w)| v.mut_add(w));
-            }
-            fn mut_sub(&mut self, rhs: &Self) {
-                self.iter_mut()
-                    .zip(rhs.into_iter())
-                    .for_each(|(v, w)| v.mut_sub(w));
-            }
-            fn mut_mul(&mut self, rhs: &Self) {
-                self.iter_mut()
-                    .zip(rhs.into_iter())
-                    .for_each(|(v, w)| v.mut_mul(w));
-            }
-            fn mut_div(&mut self, rhs: &Self) {
-                self.iter_mut()
-                    .zip(rhs.into_iter())
-                    .for_each(|(v, w)| v.mut_div(w));
-            }
-            fn mut_scal_mul(&mut self, rhs: $t) {
-                self.iter_mut().for_each(|v| v.mut_scal_mul(rhs));
-            }
-            fn mut_clamp(&mut self, min: $t, max: $t) {
-                self.iter_mut().for_each(|v| v.mut_clamp(min, max));
-            }
-            fn mut_normalized(&mut self)
-            where
-                Self: ArrayVectorSpace<$t>,
-            {
-                let n = self.norm2().sqrt();
-                self.mut_scal_mul(n.recip())
-            }
+    }
+    /// `self + rhs * factor` in one pass, with a single rounding per leaf through `mul_add`. On
+    /// targets built without hardware FMA, `mul_add` is a software routine and this is slower than
+    /// `self.add(rhs.scal_mul(factor))`.
+    fn scale_add(self, rhs: Self, factor: T) -> Self;
+    /// Per-leaf `self * a + b` with a single rounding, through `mul_add`.
+    fn fma(self, a: Self, b: Self) -> Self;
+    /// Projecting onto a zero vector gives the zero vector.
+    fn project_onto(self, onto: Self) -> Self
+    where
+        Self: Sized + Clone,
+        T: Scalar,
+    {
+        let n = onto.clone().norm2();
+        if n == T::ZERO {
+            onto.scal_mul(T::ZERO)
+        } else {
+            onto.clone().scal_mul(self.dot(onto) / n)
+        }
+    }
+    /// Rejecting from a zero vector gives `self`.
+    fn reject_from(self, from: Self) -> Self
+    where
+        Self: Sized + Clone,
+        T: Scalar,
+    {
+        self.clone().sub(self.project_onto(from))
+    }
+    /// Cosine of the angle, clamped to `[-1, 1]`. NaN if either vector is zero.
+    fn cosine_similarity(self, rhs: Self) -> T
+    where
+        Self: Sized + Clone,
+        T: Float,
+    {
+        let n = self.clone().norm() * rhs.clone().norm();
+        T::clamp(self.dot(rhs) / n, -T::ONE, T::ONE)
+    }
+    /// Angle in radians, in `[0, π]`, using Kahan's `atan2` formula which stays accurate for
+    /// nearly parallel or antiparallel vectors. NaN if either vector is zero.
+    fn angle_between(self, rhs: Self) -> T
+    where
+        Self: Sized + Clone,
+        T: Float,
+    {
+        let (n, m) = (self.clone().norm(), rhs.clone().norm());
+        if n == T::ZERO || m == T::ZERO {
+            return T::NAN;
+        }
+        let (u, v) = (self.scal_mul(m), rhs.scal_mul(n));
+        let a = T::atan2(u.clone().sub(v.clone()).norm(), u.add(v).norm());
+        a + a
+    }
+    /// Reflects about the plane orthogonal to `normal`: an incident direction pointing into the
+    /// surface comes out pointing away from it. `normal` is assumed to be of unit length;
+    /// otherwise `2 |normal|²` times the normal component is subtracted instead of twice it.
+    fn reflect(self, normal: Self) -> Self
+    where
+        Self: Sized + Clone,
+        T: Scalar,
+    {
+        let d = self.clone().dot(normal.clone());
+        self.sub(normal.scal_mul(d + d))
+    }
+    /// Refracts the unit incident direction `self` through a surface with unit `normal` facing
+    /// against it, `eta` being the ratio of refractive indices. `None` on total internal
+    /// reflection.
+    fn refract(self, normal: Self, eta: T) -> Option<Self>
+    where
+        Self: Sized + Clone,
+        T: Float,
+    {
+        let d = normal.clone().dot(self.clone());
+        let k = T::ONE - eta * eta * (T::ONE - d * d);
+        (k >= T::ZERO).then(|| {
+            self.scal_mul(eta)
+                .sub(normal.scal_mul(eta * d + T::sqrt(k)))
+        })
+    }
+    /// A zero input, scalar or array, normalizes to NaN.
+    fn normalized(self) -> Self;
+    /// Returns `None` instead of dividing by a norm that is zero or not finite. The vector is first
+    /// scaled by its largest leaf, as in `norm_p`, so huge and subnormal inputs still normalize.
+    fn try_normalized(self) -> Option<Self>
+    where
+        Self: Sized;
+    /// Whether `|norm2 - 1| < eps`.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_normalized(self, eps: T) -> bool
+    where
+        Self: Sized + Clone,
+        T: Float,
+    {
+        T::abs(self.norm2() - T::ONE) < eps
+    }
+    /// `is_normalized` with a tolerance of four epsilons of the scalar type.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_unit(self) -> bool
+    where
+        Self: Sized + Clone,
+        T: Float,
+    {
+        self.is_normalized(T::from_usize(4) * T::EPSILON)
+    }
+    /// The zero vector wherever `try_normalized` would return `None`.
+    fn normalize_or_zero(self) -> Self
+    where
+        Self: Sized + Clone,
+        T: Scalar,
+    {
+        (self.clone().try_normalized()).unwrap_or_else(|| self.map_scalar(|_| T::ZERO))
+    }
+    /// Same direction with norm `len`; a vector `normalize_or_zero` sends to zero stays zero.
+    fn with_length(self, len: T) -> Self
+    where
+        Self: Sized + Clone,
+        T: Scalar,
+    {
+        self.normalize_or_zero().scal_mul(len)
+    }
+    /// Scales `self` down to norm `max` if it is longer, keeping its direction. The norm is
+    /// taken after scaling by `norm_inf`, so huge inputs do not overflow it.
+    fn clamp_length_max(self, max: T) -> Self
+    where
+        Self: Sized + Clone,
+        T: Scalar,
+    {
+        let m = self.clone().norm_inf();
+        if m == T::ZERO {
+            return self;
+        }
+        let u = self.clone().scal_div(m);
+        let n = u.clone().norm();
+        if m * n > max {
+            u.scal_mul(max / n)
+        } else {
+            self
+        }
+    }
+    /// Scales `self` up to norm `min` if it is shorter, with the same `norm_inf` scaling as
+    /// `clamp_length_max`. A zero vector has no direction and stays zero.
+    fn clamp_length_min(self, min: T) -> Self
+    where
+        Self: Sized + Clone,
+        T: Scalar,
+    {
+        let m = self.clone().norm_inf();
+        if m == T::ZERO {
+            return self;
+        }
+        let u = self.clone().scal_div(m);
+        let n = u.clone().norm();
+        if m * n < min {
+            u.scal_mul(min / n)
+        } else {
+            self
+        }
+    }
+}
+
+impl<S: Float> ArrayVectorSpaceFloat<S> for S {
+    fn norm(self) -> S {
+        S::abs(self)
+    }
+    fn dot_compensated(self, rhs: Self) -> S {
+        self * rhs
+    }
+    fn dot_compensated_acc(self, rhs: Self, (sum, c): (S, S)) -> (S, S) {
+        let x = self * rhs;
+        let t = sum + x;
+        if S::abs(sum) >= S::abs(x) {
+            (t, c + ((sum - t) + x))
+        } else {
+            (t, c + ((x - t) + sum))
+        }
+    }
+    fn dot_pairwise_with(self, rhs: Self, _threshold: usize) -> S {
+        self * rhs
+    }
+    fn norm_l1(self) -> S {
+        S::abs(self)
+    }
+    fn norm_inf(self) -> S {
+        S::abs(self)
+    }
+    fn min_element(self) -> S {
+        self
+    }
+    fn max_element(self) -> S {
+        self
+    }
+    fn norm_p(self, _p: S) -> S {
+        S::abs(self)
+    }
+    fn norm_p_sum(self, p: S) -> S {
+        S::powf(S::abs(self), p)
+    }
+    fn abs(self) -> Self {
+        S::abs(self)
+    }
+    fn recip(self) -> Self {
+        S::recip(self)
+    }
+    fn floor(self) -> Self {
+        S::floor(self)
+    }
+    fn ceil(self) -> Self {
+        S::ceil(self)
+    }
+    fn round(self) -> Self {
+        S::round(self)
+    }
+    fn round_ties_even(self) -> Self {
+        S::round_ties_even(self)
+    }
+    fn sqrt(self) -> Self {
+        S::sqrt(self)
+    }
+    fn exp(self) -> Self {
+        S::exp(self)
+    }
+    fn ln(self) -> Self {
+        S::ln(self)
+    }
+    fn exp2(self) -> Self {
+        S::exp2(self)
+    }
+    fn log2(self) -> Self {
+        S::log2(self)
+    }
+    fn sin(self) -> Self {
+        S::sin(self)
+    }
+    fn cos(self) -> Self {
+        S::cos(self)
+    }
+    fn tan(self) -> Self {
+        S::tan(self)
+    }
+    fn asin(self) -> Self {
+        S::asin(self)
+    }
+    fn acos(self) -> Self {
+        S::acos(self)
+    }
+    fn atan(self) -> Self {
+        S::atan(self)
+    }
+    fn atan2(self, rhs: Self) -> Self {
+        S::atan2(self, rhs)
+    }
+    fn powi(self, n: i32) -> Self {
+        S::powi(self, n)
+    }
+    fn powf(self, rhs: Self) -> Self {
+        S::powf(self, rhs)
+    }
+    fn powf_scalar(self, exp: S) -> Self {
+        S::powf(self, exp)
+    }
+    fn lerp(self, rhs: Self, t: S) -> Self {
+        if t == S::ZERO {
+            self
+        } else if t == S::ONE {
+            rhs
+        } else if t < S::ONE / (S::ONE + S::ONE) {
+            self + (rhs - self) * t
+        } else {
+            rhs - (rhs - self) * (S::ONE - t)
         }
-    };
+    }
+    fn scale_add(self, rhs: Self, factor: S) -> Self {
+        S::mul_add(rhs, factor, self)
+    }
+    fn fma(self, a: Self, b: Self) -> Self {
+        S::mul_add(self, a, b)
+    }
+    fn normalized(self) -> Self {
+        self / S::abs(self)
+    }
+    fn try_normalized(self) -> Option<Self> {
+        (self != S::ZERO && S::is_finite(self)).then(|| self.normalized())
+    }
 }
 
-impl_vector_space! {f32}
-impl_vector_space! {f64}
+impl<S: Float, const N: usize, V: ArrayVectorSpaceFloat<S> + ArrayVectorSpaceInit<S> + Copy>
+    ArrayVectorSpaceFloat<S> for [V; N]
+{
+    fn norm(self) -> S {
+        S::sqrt(self.norm2())
+    }
+    fn dot_compensated(self, rhs: Self) -> S {
+        let (sum, c) = self.dot_compensated_acc(rhs, (S::ZERO, S::ZERO));
+        sum + c
+    }
+    fn dot_compensated_acc(self, rhs: Self, acc: (S, S)) -> (S, S) {
+        self.into_iter()
+            .zip(rhs)
+            .fold(acc, |acc, (v, w)| v.dot_compensated_acc(w, acc))
+    }
+    fn dot_pairwise_with(self, rhs: Self, threshold: usize) -> S {
+        pairwise_dot(&self, &rhs, threshold)
+    }
+    fn norm_l1(self) -> S {
+        self.into_iter()
+            .map(|v| v.norm_l1())
+            .fold(S::ZERO, |a, b| a + b)
+    }
+    fn norm_inf(self) -> S {
+        self.into_iter()
+            .map(|v| v.norm_inf())
+            .fold(S::ZERO, |m, n| if n > m || S::is_nan(n) { n } else { m })
+    }
+    fn min_element(self) -> S {
+        self.into_iter()
+            .map(|v| v.min_element())
+            .fold(S::INFINITY, S::min)
+    }
+    fn max_element(self) -> S {
+        self.into_iter()
+            .map(|v| v.max_element())
+            .fold(S::NEG_INFINITY, S::max)
+    }
+    fn norm_p(self, p: S) -> S {
+        let m = self.norm_inf();
+        if m == S::ZERO || !S::is_finite(m) || p == S::INFINITY {
+            return m;
+        }
+        m * S::powf(self.scal_div(m).norm_p_sum(p), S::recip(p))
+    }
+    fn norm_p_sum(self, p: S) -> S {
+        self.into_iter()
+            .map(|v| v.norm_p_sum(p))
+            .fold(S::ZERO, |a, b| a + b)
+    }
+    fn abs(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.abs());
+        self
+    }
+    fn recip(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.recip());
+        self
+    }
+    fn floor(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.floor());
+        self
+    }
+    fn ceil(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.ceil());
+        self
+    }
+    fn round(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.round());
+        self
+    }
+    fn round_ties_even(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.round_ties_even());
+        self
+    }
+    fn sqrt(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.sqrt());
+        self
+    }
+    fn exp(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.exp());
+        self
+    }
+    fn ln(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.ln());
+        self
+    }
+    fn exp2(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.exp2());
+        self
+    }
+    fn log2(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.log2());
+        self
+    }
+    fn sin(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.sin());
+        self
+    }
+    fn cos(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.cos());
+        self
+    }
+    fn tan(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.tan());
+        self
+    }
+    fn asin(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.asin());
+        self
+    }
+    fn acos(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.acos());
+        self
+    }
+    fn atan(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.atan());
+        self
+    }
+    fn atan2(mut self, rhs: Self) -> Self {
+        self.iter_mut().zip(rhs).for_each(|(v, w)| *v = v.atan2(w));
+        self
+    }
+    fn powi(mut self, n: i32) -> Self {
+        self.iter_mut().for_each(|v| *v = v.powi(n));
+        self
+    }
+    fn powf(mut self, rhs: Self) -> Self {
+        self.iter_mut().zip(rhs).for_each(|(v, w)| *v = v.powf(w));
+        self
+    }
+    fn powf_scalar(mut self, exp: S) -> Self {
+        self.iter_mut().for_each(|v| *v = v.powf_scalar(exp));
+        self
+    }
+    fn lerp(mut self, rhs: Self, t: S) -> Self {
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| *v = v.lerp(w, t));
+        self
+    }
+    fn scale_add(mut self, rhs: Self, factor: S) -> Self {
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| *v = v.scale_add(w, factor));
+        self
+    }
+    fn fma(mut self, a: Self, b: Self) -> Self {
+        self.iter_mut()
+            .zip(a.into_iter().zip(b))
+            .for_each(|(v, (a, b))| *v = v.fma(a, b));
+        self
+    }
+    fn normalized(self) -> Self {
+        let n = self.norm();
+        self.scal_mul(S::recip(n))
+    }
+    fn try_normalized(self) -> Option<Self> {
+        let m = self.norm_inf();
+        if m == S::ZERO || !S::is_finite(m) {
+            return None;
+        }
+        let v = self.scal_div(m);
+        Some(v.scal_mul(S::recip(v.norm())))
+    }
+}
+
+pub trait ArrayVectorSpaceMut<T> {
+    fn mut_add(&mut self, rhs: &Self);
+    fn mut_sub(&mut self, rhs: &Self);
+    fn mut_mul(&mut self, rhs: &Self);
+    fn mut_div(&mut self, rhs: &Self);
+    fn mut_neg(&mut self)
+    where
+        T: Neg<Output = T>;
+    fn mut_scal_mul(&mut self, rhs: T);
+    fn mut_scal_div(&mut self, rhs: T);
+    fn mut_scal_add(&mut self, rhs: T);
+    fn mut_scal_sub(&mut self, rhs: T);
+    fn mut_clamp(&mut self, min: T, max: T);
+    fn mut_clamp_vec(&mut self, min: &Self, max: &Self);
+    fn mut_signum(&mut self);
+    fn mut_min_comp(&mut self, rhs: &Self);
+    fn mut_max_comp(&mut self, rhs: &Self);
+    /// Per-element kernels used by the array and slice impls, so that scalar leaves can take
+    /// the `simd` path.
+    #[doc(hidden)]
+    fn mut_add_slice(x: &mut [Self], y: &[Self])
+    where
+        Self: Sized,
+    {
+        x.iter_mut().zip(y).for_each(|(v, w)| v.mut_add(w));
+    }
+    #[doc(hidden)]
+    fn mut_mul_slice(x: &mut [Self], y: &[Self])
+    where
+        Self: Sized,
+    {
+        x.iter_mut().zip(y).for_each(|(v, w)| v.mut_mul(w));
+    }
+    #[doc(hidden)]
+    fn mut_scal_mul_slice(x: &mut [Self], rhs: T)
+    where
+        Self: Sized,
+        T: Copy,
+    {
+        x.iter_mut().for_each(|v| v.mut_scal_mul(rhs));
+    }
+}
+
+impl<S: Scalar> ArrayVectorSpaceMut<S> for S {
+    fn mut_add(&mut self, rhs: &Self) {
+        *self += *rhs
+    }
+    fn mut_sub(&mut self, rhs: &Self) {
+        *self -= *rhs
+    }
+    fn mut_mul(&mut self, rhs: &Self) {
+        *self *= *rhs
+    }
+    fn mut_div(&mut self, rhs: &Self) {
+        *self /= *rhs
+    }
+    fn mut_neg(&mut self)
+    where
+        S: Neg<Output = S>,
+    {
+        *self = -*self
+    }
+    fn mut_scal_mul(&mut self, rhs: S) {
+        *self *= rhs
+    }
+    fn mut_scal_div(&mut self, rhs: S) {
+        *self /= rhs
+    }
+    fn mut_scal_add(&mut self, rhs: S) {
+        *self += rhs
+    }
+    fn mut_scal_sub(&mut self, rhs: S) {
+        *self -= rhs
+    }
+    fn mut_clamp(&mut self, min: S, max: S) {
+        *self = S::clamp(*self, min, max);
+    }
+    fn mut_clamp_vec(&mut self, min: &Self, max: &Self) {
+        *self = S::clamp(*self, *min, *max);
+    }
+    fn mut_signum(&mut self) {
+        *self = S::signum(*self)
+    }
+    fn mut_min_comp(&mut self, rhs: &Self) {
+        *self = S::min(*self, *rhs);
+    }
+    fn mut_max_comp(&mut self, rhs: &Self) {
+        *self = S::max(*self, *rhs);
+    }
+    fn mut_add_slice(x: &mut [S], y: &[S]) {
+        S::add_slice(x, y)
+    }
+    fn mut_mul_slice(x: &mut [S], y: &[S]) {
+        S::mul_slice(x, y)
+    }
+    fn mut_scal_mul_slice(x: &mut [S], rhs: S) {
+        S::scal_mul_slice(x, rhs)
+    }
+}
+
+impl<S: Scalar, const N: usize, V: ArrayVectorSpaceMut<S> + Copy> ArrayVectorSpaceMut<S>
+    for [V; N]
+{
+    fn mut_add(&mut self, rhs: &Self) {
+        V::mut_add_slice(self, rhs)
+    }
+    fn mut_sub(&mut self, rhs: &Self) {
+        self.iter_mut().zip(rhs).for_each(|(v, w)| v.mut_sub(w));
+    }
+    fn mut_mul(&mut self, rhs: &Self) {
+        V::mut_mul_slice(self, rhs)
+    }
+    fn mut_div(&mut self, rhs: &Self) {
+        self.iter_mut().zip(rhs).for_each(|(v, w)| v.mut_div(w));
+    }
+    fn mut_neg(&mut self)
+    where
+        S: Neg<Output = S>,
+    {
+        self.iter_mut().for_each(|v| v.mut_neg());
+    }
+    fn mut_scal_mul(&mut self, rhs: S) {
+        V::mut_scal_mul_slice(self, rhs)
+    }
+    fn mut_scal_div(&mut self, rhs: S) {
+        self.iter_mut().for_each(|v| v.mut_scal_div(rhs));
+    }
+    fn mut_scal_add(&mut self, rhs: S) {
+        self.iter_mut().for_each(|v| v.mut_scal_add(rhs));
+    }
+    fn mut_scal_sub(&mut self, rhs: S) {
+        self.iter_mut().for_each(|v| v.mut_scal_sub(rhs));
+    }
+    fn mut_clamp(&mut self, min: S, max: S) {
+        self.iter_mut().for_each(|v| v.mut_clamp(min, max));
+    }
+    fn mut_clamp_vec(&mut self, min: &Self, max: &Self) {
+        self.iter_mut()
+            .zip(min.iter().zip(max.iter()))
+            .for_each(|(v, (a, b))| v.mut_clamp_vec(a, b));
+    }
+    fn mut_signum(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_signum());
+    }
+    fn mut_min_comp(&mut self, rhs: &Self) {
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| v.mut_min_comp(w));
+    }
+    fn mut_max_comp(&mut self, rhs: &Self) {
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| v.mut_max_comp(w));
+    }
+}
+
+pub trait ArrayVectorSpaceFloatMut<T>: ArrayVectorSpaceMut<T> {
+    fn mut_abs(&mut self);
+    fn mut_recip(&mut self);
+    fn mut_floor(&mut self);
+    fn mut_ceil(&mut self);
+    fn mut_round(&mut self);
+    fn mut_round_ties_even(&mut self);
+    fn mut_sqrt(&mut self);
+    fn mut_exp(&mut self);
+    fn mut_ln(&mut self);
+    fn mut_exp2(&mut self);
+    fn mut_log2(&mut self);
+    fn mut_sin(&mut self);
+    fn mut_cos(&mut self);
+    fn mut_tan(&mut self);
+    fn mut_asin(&mut self);
+    fn mut_acos(&mut self);
+    fn mut_atan(&mut self);
+    fn mut_atan2(&mut self, rhs: &Self);
+    fn mut_powi(&mut self, n: i32);
+    fn mut_powf(&mut self, rhs: &Self);
+    fn mut_powf_scalar(&mut self, exp: T);
+    fn mut_lerp(&mut self, rhs: &Self, t: T);
+    fn mut_scale_add(&mut self, rhs: &Self, factor: T);
+    fn mut_fma(&mut self, a: &Self, b: &Self);
+    fn mut_reflect(&mut self, normal: &Self)
+    where
+        Self: ArrayVectorSpaceFloat<T> + Clone,
+        T: Scalar,
+    {
+        *self = self.clone().reflect(normal.clone())
+    }
+    fn mut_normalized(&mut self)
+    where
+        Self: ArrayVectorSpaceFloat<T>;
+    /// Leaves `self` untouched and returns `false` when `try_normalized` would return `None`.
+    fn mut_try_normalized(&mut self) -> bool
+    where
+        Self: ArrayVectorSpaceFloat<T>;
+    fn mut_normalize_or_zero(&mut self)
+    where
+        Self: ArrayVectorSpaceFloat<T> + Clone,
+        T: Scalar,
+    {
+        if !self.mut_try_normalized() {
+            *self = self.clone().map_scalar(|_| T::ZERO)
+        }
+    }
+    fn mut_set_length(&mut self, len: T)
+    where
+        Self: ArrayVectorSpaceFloat<T> + Clone,
+        T: Scalar,
+    {
+        self.mut_normalize_or_zero();
+        self.mut_scal_mul(len)
+    }
+    fn mut_clamp_length_max(&mut self, max: T)
+    where
+        Self: ArrayVectorSpaceFloat<T> + Clone,
+        T: Scalar,
+    {
+        *self = self.clone().clamp_length_max(max)
+    }
+    fn mut_clamp_length_min(&mut self, min: T)
+    where
+        Self: ArrayVectorSpaceFloat<T> + Clone,
+        T: Scalar,
+    {
+        *self = self.clone().clamp_length_min(min)
+    }
+}
+
+impl<S: Float> ArrayVectorSpaceFloatMut<S> for S {
+    fn mut_abs(&mut self) {
+        *self = S::abs(*self)
+    }
+    fn mut_recip(&mut self) {
+        *self = S::recip(*self)
+    }
+    fn mut_floor(&mut self) {
+        *self = S::floor(*self)
+    }
+    fn mut_ceil(&mut self) {
+        *self = S::ceil(*self)
+    }
+    fn mut_round(&mut self) {
+        *self = S::round(*self)
+    }
+    fn mut_round_ties_even(&mut self) {
+        *self = S::round_ties_even(*self)
+    }
+    fn mut_sqrt(&mut self) {
+        *self = S::sqrt(*self)
+    }
+    fn mut_exp(&mut self) {
+        *self = S::exp(*self)
+    }
+    fn mut_ln(&mut self) {
+        *self = S::ln(*self)
+    }
+    fn mut_exp2(&mut self) {
+        *self = S::exp2(*self)
+    }
+    fn mut_log2(&mut self) {
+        *self = S::log2(*self)
+    }
+    fn mut_sin(&mut self) {
+        *self = S::sin(*self)
+    }
+    fn mut_cos(&mut self) {
+        *self = S::cos(*self)
+    }
+    fn mut_tan(&mut self) {
+        *self = S::tan(*self)
+    }
+    fn mut_asin(&mut self) {
+        *self = S::asin(*self)
+    }
+    fn mut_acos(&mut self) {
+        *self = S::acos(*self)
+    }
+    fn mut_atan(&mut self) {
+        *self = S::atan(*self)
+    }
+    fn mut_atan2(&mut self, rhs: &Self) {
+        *self = S::atan2(*self, *rhs)
+    }
+    fn mut_powi(&mut self, n: i32) {
+        *self = S::powi(*self, n)
+    }
+    fn mut_powf(&mut self, rhs: &Self) {
+        *self = S::powf(*self, *rhs)
+    }
+    fn mut_powf_scalar(&mut self, exp: S) {
+        *self = S::powf(*self, exp)
+    }
+    fn mut_lerp(&mut self, rhs: &Self, t: S) {
+        *self = self.lerp(*rhs, t)
+    }
+    fn mut_scale_add(&mut self, rhs: &Self, factor: S) {
+        *self = S::mul_add(*rhs, factor, *self)
+    }
+    fn mut_fma(&mut self, a: &Self, b: &Self) {
+        *self = S::mul_add(*self, *a, *b)
+    }
+    fn mut_normalized(&mut self) {
+        *self /= S::abs(*self)
+    }
+    fn mut_try_normalized(&mut self) -> bool {
+        match self.try_normalized() {
+            Some(v) => {
+                *self = v;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<S: Float, const N: usize, V: ArrayVectorSpaceFloatMut<S> + Copy> ArrayVectorSpaceFloatMut<S>
+    for [V; N]
+{
+    fn mut_abs(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_abs());
+    }
+    fn mut_recip(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_recip());
+    }
+    fn mut_floor(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_floor());
+    }
+    fn mut_ceil(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_ceil());
+    }
+    fn mut_round(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_round());
+    }
+    fn mut_round_ties_even(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_round_ties_even());
+    }
+    fn mut_sqrt(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_sqrt());
+    }
+    fn mut_exp(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_exp());
+    }
+    fn mut_ln(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_ln());
+    }
+    fn mut_exp2(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_exp2());
+    }
+    fn mut_log2(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_log2());
+    }
+    fn mut_sin(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_sin());
+    }
+    fn mut_cos(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_cos());
+    }
+    fn mut_tan(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_tan());
+    }
+    fn mut_asin(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_asin());
+    }
+    fn mut_acos(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_acos());
+    }
+    fn mut_atan(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_atan());
+    }
+    fn mut_atan2(&mut self, rhs: &Self) {
+        self.iter_mut().zip(rhs).for_each(|(v, w)| v.mut_atan2(w));
+    }
+    fn mut_powi(&mut self, n: i32) {
+        self.iter_mut().for_each(|v| v.mut_powi(n));
+    }
+    fn mut_powf(&mut self, rhs: &Self) {
+        self.iter_mut().zip(rhs).for_each(|(v, w)| v.mut_powf(w));
+    }
+    fn mut_powf_scalar(&mut self, exp: S) {
+        self.iter_mut().for_each(|v| v.mut_powf_scalar(exp));
+    }
+    fn mut_lerp(&mut self, rhs: &Self, t: S) {
+        self.iter_mut().zip(rhs).for_each(|(v, w)| v.mut_lerp(w, t));
+    }
+    fn mut_scale_add(&mut self, rhs: &Self, factor: S) {
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| v.mut_scale_add(w, factor));
+    }
+    fn mut_fma(&mut self, a: &Self, b: &Self) {
+        self.iter_mut()
+            .zip(a.iter().zip(b))
+            .for_each(|(v, (a, b))| v.mut_fma(a, b));
+    }
+    fn mut_normalized(&mut self)
+    where
+        Self: ArrayVectorSpaceFloat<S>,
+    {
+        let n = self.norm();
+        self.mut_scal_mul(S::recip(n))
+    }
+    fn mut_try_normalized(&mut self) -> bool
+    where
+        Self: ArrayVectorSpaceFloat<S>,
+    {
+        let m = self.norm_inf();
+        if m == S::ZERO || !S::is_finite(m) {
+            return false;
+        }
+        self.mut_scal_div(m);
+        let n = self.norm();
+        self.mut_scal_mul(S::recip(n));
+        true
+    }
+}
+
+/// Operations that need an integer scalar, where overflow is reported instead of wrapping.
+pub trait ArrayVectorSpaceInteger<T>: ArrayVectorSpace<T> {
+    /// `dot`, or `None` if a product or a partial sum overflows `T`.
+    fn checked_dot(self, rhs: Self) -> Option<T>;
+    fn checked_norm2(self) -> Option<T>
+    where
+        Self: Sized + Clone,
+    {
+        self.clone().checked_dot(self)
+    }
+    /// Per-leaf `x / |x|`: `-1`, `0` or `1` for signed types, and `0` or `1` for unsigned ones,
+    /// since an integer vector has no unit-length counterpart.
+    fn normalized(self) -> Self;
+}
+
+impl<S: Integer> ArrayVectorSpaceInteger<S> for S {
+    fn checked_dot(self, rhs: Self) -> Option<S> {
+        S::checked_mul(self, rhs)
+    }
+    fn normalized(self) -> Self {
+        S::signum(self)
+    }
+}
+
+impl<S: Integer, const N: usize, V: ArrayVectorSpaceInteger<S> + ArrayVectorSpaceInit<S> + Copy>
+    ArrayVectorSpaceInteger<S> for [V; N]
+{
+    fn checked_dot(self, rhs: Self) -> Option<S> {
+        self.into_iter()
+            .zip(rhs)
+            .try_fold(S::ZERO, |a, (v, w)| S::checked_add(a, v.checked_dot(w)?))
+    }
+    fn normalized(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.normalized());
+        self
+    }
+}
+
+/// Leaf predicates, kept out of `ArrayVectorSpace` since they do not return `Self`.
+pub trait ArrayVectorSpacePredicates<T> {
+    /// Whether `f` holds for every leaf, stopping at the first `false` across all nesting levels.
+    fn all<F: FnMut(T) -> bool>(self, f: F) -> bool;
+    /// Whether `f` holds for some leaf, stopping at the first `true` across all nesting levels.
+    fn any<F: FnMut(T) -> bool>(self, f: F) -> bool;
+    /// Whether every leaf is within `eps` of zero, i.e. `|x| < eps`.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_zero(self, eps: T) -> bool
+    where
+        Self: Sized,
+        T: Float,
+    {
+        self.all(|x| T::abs(x) < eps)
+    }
+    /// Whether every leaf compares equal to zero, so `-0.0` counts as zero and NaN does not.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_exactly_zero(self) -> bool
+    where
+        Self: Sized,
+        T: Scalar,
+    {
+        self.all(|x| x == T::ZERO)
+    }
+}
+
+impl<S: Scalar> ArrayVectorSpacePredicates<S> for S {
+    fn all<F: FnMut(S) -> bool>(self, mut f: F) -> bool {
+        f(self)
+    }
+    fn any<F: FnMut(S) -> bool>(self, mut f: F) -> bool {
+        f(self)
+    }
+}
+
+impl<S: Scalar, const N: usize, V: ArrayVectorSpacePredicates<S>> ArrayVectorSpacePredicates<S>
+    for [V; N]
+{
+    fn all<F: FnMut(S) -> bool>(self, mut f: F) -> bool {
+        self.into_iter().all(|v| v.all(&mut f))
+    }
+    fn any<F: FnMut(S) -> bool>(self, mut f: F) -> bool {
+        self.into_iter().any(|v| v.any(&mut f))
+    }
+}
+
+pub trait CrossProduct<T> {
+    fn cross(self, rhs: Self) -> Self;
+    fn mut_cross(&mut self, rhs: &Self);
+}
+
+/// For nested `V`, the cross product is taken component-wise across the three `V`s.
+impl<T, V: ArrayVectorSpace<T> + Copy> CrossProduct<T> for [V; 3] {
+    fn cross(self, rhs: Self) -> Self {
+        [
+            self[1].mul(rhs[2]).sub(self[2].mul(rhs[1])),
+            self[2].mul(rhs[0]).sub(self[0].mul(rhs[2])),
+            self[0].mul(rhs[1]).sub(self[1].mul(rhs[0])),
+        ]
+    }
+    fn mut_cross(&mut self, rhs: &Self) {
+        *self = self.cross(*rhs)
+    }
+}
+
+pub trait PerpProduct<T> {
+    /// Rotates by 90° counter-clockwise.
+    fn perp(self) -> Self;
+    /// `self.perp().dot(rhs)`, positive when `rhs` is counter-clockwise from `self`.
+    fn perp_dot(self, rhs: Self) -> T;
+}
+
+impl<S: Float> PerpProduct<S> for [S; 2] {
+    fn perp(self) -> Self {
+        [-self[1], self[0]]
+    }
+    fn perp_dot(self, rhs: Self) -> S {
+        self[0] * rhs[1] - self[1] * rhs[0]
+    }
+}
+
+#[cfg(all(test, any(feature = "std", feature = "libm")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn norm() {
+        assert_eq!([3.0_f32, 4.0].norm(), 5.0);
+        assert_eq!((-2.5_f32).norm(), 2.5);
+        assert_eq!(2.5_f64.norm(), 2.5);
+    }
+
+    #[test]
+    fn try_normalized() {
+        assert_eq!([0.0_f64; 4].try_normalized(), None);
+        assert_eq!(0.0_f64.try_normalized(), None);
+        assert_eq!([0.0_f64, 2.0].try_normalized(), Some([0.0, 1.0]));
+        assert_eq!([1e-310_f64, 0.0].try_normalized(), Some([1.0, 0.0]));
+        assert_eq!([1e200_f64, 0.0].try_normalized(), Some([1.0, 0.0]));
+        assert_eq!([[4e300_f64], [0.0]].try_normalized(), Some([[1.0], [0.0]]));
+        assert_eq!([f64::NAN, 1.0].try_normalized(), None);
+        assert_eq!([f64::INFINITY, 1.0].try_normalized(), None);
+
+        let mut v = [[0.0_f64], [-4.0]];
+        assert!(v.mut_try_normalized());
+        assert_eq!(v, [[0.0], [-1.0]]);
+        let mut v = [1e200_f64, 0.0];
+        assert!(v.mut_try_normalized());
+        assert_eq!(v, [1.0, 0.0]);
+        let mut v = [f64::NAN, 0.0];
+        assert!(!v.mut_try_normalized());
+        assert!(v[0].is_nan());
+    }
+
+    #[test]
+    fn norm_l1() {
+        assert_eq!([1.0_f32, -2.0, 3.0].norm_l1(), 6.0);
+        let m = [[1.0_f32, -1.0, 2.0]; 4];
+        assert_eq!(m.norm_l1(), 16.0);
+    }
+
+    #[test]
+    fn scal_div() {
+        assert_eq!([2.0_f64, 4.0].scal_div(2.0), [1.0, 2.0]);
+        assert_eq!(
+            [1.0_f64, -1.0].scal_div(0.0),
+            [f64::INFINITY, f64::NEG_INFINITY]
+        );
+        assert!(0.0_f64.scal_div(0.0).is_nan());
+        let mut v = [[6.0_f32, 3.0]; 2];
+        v.mut_scal_div(3.0);
+        assert_eq!(v, [[2.0, 1.0]; 2]);
+    }
+
+    #[test]
+    fn norm_inf() {
+        assert_eq!([1.0_f64, -7.0, 3.0].norm_inf(), 7.0);
+        assert_eq!([[1.0_f64, 2.0], [-5.0, 0.5]].norm_inf(), 5.0);
+        assert_eq!(([] as [f64; 0]).norm_inf(), 0.0);
+        assert!([f64::NAN, 1.0].norm_inf().is_nan());
+        assert!([1.0, f64::NAN].norm_inf().is_nan());
+        assert!([[9.0_f64], [f64::NAN], [1.0]].norm_inf().is_nan());
+    }
+
+    #[test]
+    fn normalized_scalar_matches_array() {
+        for x in [3.0_f64, -3.0, 0.5, -1e-3] {
+            assert_eq!(x.normalized(), [x].normalized()[0]);
+            assert_eq!([[x]].normalized(), [[x.normalized()]]);
+            let (mut s, mut a) = (x, [x]);
+            s.mut_normalized();
+            a.mut_normalized();
+            assert_eq!([s], a);
+        }
+        assert_eq!((-3.0_f64).normalized(), -1.0);
+        assert!(0.0_f64.normalized().is_nan());
+        assert!([0.0_f64].normalized()[0].is_nan());
+    }
+
+    #[test]
+    fn neg() {
+        assert_eq!([1.0_f64, -2.0, 3.0].neg(), [-1.0, 2.0, -3.0]);
+        let mut v = [[1.0_f32, -2.0]; 2];
+        v.mut_neg();
+        assert_eq!(v, [[-1.0, 2.0]; 2]);
+    }
+
+    #[test]
+    fn norm_f64() {
+        assert_eq!([3.0_f64, 4.0].norm(), 5.0);
+        assert_eq!([[3.0_f64], [4.0]].norm(), 5.0);
+        assert_eq!([0.0_f64, -4.0].normalized(), [0.0, -1.0]);
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!([1_i64, 2, 3, 4].dot([4, 3, 2, 1]), 20);
+        assert_eq!([1_u64, 2].add([3, 4]).sub([1, 1]), [3, 5]);
+        assert_eq!([[2_u32, 3]; 2].mul([[5, 7]; 2]), [[10, 21]; 2]);
+        assert_eq!([-1_i32, 2].scal_mul(3), [-3, 6]);
+        assert_eq!([3_i32, 4].clamp_scalar(0, 2), [2, 2]);
+        assert_eq!([-5_i64, 1, 9].clamp_scalar(-1, 5), [-1, 1, 5]);
+        assert_eq!(ArrayVectorSpace::clamp([7_u32, 0], 1, 3), [3, 1]);
+        assert_eq!([[i64::MIN, i64::MAX]].clamp_scalar(0, 10), [[0, 10]]);
+    }
+
+    #[test]
+    fn scal_div_integer() {
+        assert_eq!([7_i32, -9].scal_div(2), [3, -4]);
+        let mut v = [[6_u32, 3]; 2];
+        v.mut_scal_div(3);
+        assert_eq!(v, [[2, 1]; 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scal_div_integer_by_zero() {
+        let zero = core::hint::black_box(0);
+        [1_i64, 2].scal_div(zero);
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!([0.0_f32, 0.0].distance([3.0, 4.0]), 5.0);
+        assert_eq!([0.0_f32, 0.0].distance2([3.0, 4.0]), 25.0);
+        assert_eq!([[1.0_f64], [1.0]].distance([[4.0], [5.0]]), 5.0);
+        assert_eq!([2_i32, 3].distance2([5, 7]), 25);
+    }
+
+    #[test]
+    fn dot_compensated() {
+        let mut x = [[1.0_f32; 1024]; 8];
+        x[0][0] = 1e8;
+        x[7][1023] = -1e8;
+        let ones = [[1.0_f32; 1024]; 8];
+        let reference: f64 = x.iter().flatten().map(|&v| v as f64).sum();
+        assert_eq!(reference, 8190.0);
+        assert_eq!(x.dot_compensated(ones) as f64, reference);
+        assert_eq!(
+            x.scal_mul(1.0).dot_compensated(ones),
+            x.dot_compensated(ones)
+        );
+
+        let y = [3.0_f64, 4.0];
+        assert_eq!(y.norm2_compensated(), 25.0);
+    }
+
+    #[test]
+    fn lerp() {
+        let (a, b) = ([1.0_f64, -2.0], [3.0, 2.0]);
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), [2.0, 0.0]);
+        assert_eq!(a.lerp(b, 2.0), [5.0, 6.0]);
+        assert_eq!(a.lerp(b, -1.0), [-1.0, -6.0]);
+        let mut m = [a, b];
+        m.mut_lerp(&[b, a], 0.5);
+        assert_eq!(m, [[2.0, 0.0]; 2]);
+    }
+
+    #[test]
+    fn cross() {
+        let (x, y, z) = ([1.0_f32, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]);
+        assert_eq!(x.cross(y), z);
+        assert_eq!(y.cross(z), x);
+        assert_eq!(z.cross(x), y);
+        assert_eq!(y.cross(x), z.neg());
+        let mut v = [1.0_f64, 2.0, 3.0];
+        v.mut_cross(&[4.0, 5.0, 6.0]);
+        assert_eq!(v, [-3.0, 6.0, -3.0]);
+    }
+
+    #[test]
+    fn dot_pairwise() {
+        let x = [0.1_f32; 1 << 14];
+        let ones = [1.0_f32; 1 << 14];
+        let reference = 0.1_f32 as f64 * (1 << 14) as f64;
+        let error = |v: f32| (v as f64 - reference).abs() / reference;
+        let sequential = x.dot_pairwise_with(ones, usize::MAX);
+        assert!(error(x.dot_pairwise(ones)) < 1e-6);
+        assert!(error(x.dot_pairwise(ones)) < error(sequential) / 10.0);
+        assert_eq!(x.dot_pairwise_with(ones, 0), x.dot_pairwise_with(ones, 1));
+
+        let m = [[0.1_f32; 128]; 128];
+        assert!(error(m.norm2_pairwise() * 10.0) < 1e-6);
+        assert_eq!(m.dot_pairwise_with(m, usize::MAX), m.dot_pairwise(m));
+    }
+
+    #[test]
+    fn distance_to_self() {
+        assert_eq!([3.0_f32, 0.0].distance([0.0, 4.0]), 5.0);
+        assert_eq!(3.0_f64.distance(-1.0), 4.0);
+        assert_eq!((-2.0_f32).distance2(1.0), 9.0);
+        for v in [[-1.5_f64, 0.0], [0.0, -0.0], [1e300, -1e-300]] {
+            let d = v.distance(v);
+            assert_eq!(d, 0.0);
+            assert!(d.is_sign_positive());
+        }
+    }
+
+    #[test]
+    fn norm_nested() {
+        let a = [[1.0_f64, 2.0, 2.0], [0.0, 0.0, 0.0]];
+        let b = [[1.0_f64, 2.0, 2.0], [4.0, 0.0, 0.0]];
+        assert_eq!(a.norm(), 3.0);
+        assert_eq!(a.distance2(b), 16.0);
+        assert_eq!(a.distance(b), 4.0);
+        assert_eq!([[2.0_f32; 2]; 2].norm(), 4.0);
+        assert_eq!([[0.0_f32; 2], [3.0, 4.0]].distance([[0.0; 2]; 2]), 5.0);
+    }
+
+    #[test]
+    fn lerp_halfway() {
+        assert_eq!(2.0_f64.lerp(4.0, 0.5), 3.0);
+        assert_eq!(
+            [[0.0_f32], [2.0]].lerp([[1.0], [-2.0]], 0.5),
+            [[0.5], [0.0]]
+        );
+        assert_eq!([0.0_f32, 2.0].lerp([1.0, 4.0], 0.5), [0.5, 3.0]);
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!([-1.0_f32, 2.0, -3.0].abs(), [1.0, 2.0, 3.0]);
+        let mut v = [[-0.5_f64], [0.5]];
+        v.mut_abs();
+        assert_eq!(v, [[0.5], [0.5]]);
+    }
+
+    #[test]
+    fn project_onto() {
+        assert_eq!([1.0_f64, 1.0].project_onto([1.0, 0.0]), [1.0, 0.0]);
+        assert_eq!([1.0_f64, 1.0].reject_from([1.0, 0.0]), [0.0, 1.0]);
+        assert_eq!([2.0_f64, 3.0].project_onto([0.0, 0.0]), [0.0, 0.0]);
+        assert_eq!([2.0_f64, 3.0].reject_from([0.0, 0.0]), [2.0, 3.0]);
+        assert_eq!(
+            [[3.0_f32], [1.0]].project_onto([[0.0], [2.0]]),
+            [[0.0], [1.0]]
+        );
+    }
+
+    #[test]
+    fn norm_orderings() {
+        let (a, b) = ([6.0_f64, 0.0, 0.0, 0.0], [-2.0_f64, 2.0, 2.0, -2.0]);
+        assert_eq!((a.norm_l1(), a.norm(), a.norm_inf()), (6.0, 6.0, 6.0));
+        assert_eq!((b.norm_l1(), b.norm(), b.norm_inf()), (8.0, 4.0, 2.0));
+        assert!(a.norm_l1() < b.norm_l1());
+        assert!(a.norm() > b.norm());
+        assert!(a.norm_inf() > b.norm_inf());
+        assert_eq!([[3.0_f32, -4.0]; 2].norm_l1(), 14.0);
+        assert_eq!([[3.0_f32, -4.0]; 2].norm_inf(), 4.0);
+    }
+
+    #[test]
+    fn reflect() {
+        assert_eq!([1.0_f32, -1.0].reflect([0.0, 1.0]), [1.0, 1.0]);
+        let mut v = [1.0_f64, -1.0, 2.0];
+        v.mut_reflect(&[0.0, 0.0, -1.0]);
+        assert_eq!(v, [1.0, -1.0, -2.0]);
+    }
+
+    #[test]
+    fn min_max_comp() {
+        assert_eq!([1.0_f64, 5.0].min_comp([3.0, 2.0]), [1.0, 2.0]);
+        assert_eq!([1.0_f64, 5.0].max_comp([3.0, 2.0]), [3.0, 5.0]);
+        assert_eq!([[1_i32, 5]].max_comp([[3, 2]]), [[3, 5]]);
+        let mut v = [1.0_f32, 5.0];
+        v.mut_min_comp(&[3.0, 2.0]);
+        assert_eq!(v, [1.0, 2.0]);
+        v.mut_max_comp(&[0.0, 4.0]);
+        assert_eq!(v, [1.0, 4.0]);
+    }
+
+    #[test]
+    fn norm_p() {
+        let v = [[3.0_f64, -4.0], [1.0, 0.5]];
+        let close = |a: f64, b: f64| (a - b).abs() <= 1e-12 * b;
+        assert!(close(v.norm_p(1.0), v.norm_l1()));
+        assert!(close(v.norm_p(2.0), v.norm()));
+        assert!(close(v.norm_p(1000.0), v.norm_inf()));
+        assert_eq!(v.norm_p(f64::INFINITY), v.norm_inf());
+        assert_eq!([1e200_f64, 1e200].norm_p(2.0), 2f64.sqrt() * 1e200);
+        assert_eq!([0.0_f32; 3].norm_p(3.0), 0.0);
+        assert_eq!((-2.0_f32).norm_p(7.0), 2.0);
+    }
+
+    #[test]
+    fn angle_between() {
+        use core::f64::consts::FRAC_PI_2;
+
+        assert_eq!([1.0_f64, 0.0].angle_between([0.0, 3.0]), FRAC_PI_2);
+        assert_eq!([0.3_f64, 0.7, 0.1].angle_between([0.3, 0.7, 0.1]), 0.0);
+        let near = [1.0_f64, 1e-9].angle_between([1.0, 0.0]);
+        assert!(!near.is_nan() && near < 1e-8);
+        assert!([1.0_f32, 2.0].angle_between([-2.0, -4.0]) > 3.0);
+    }
+
+    #[test]
+    fn sum() {
+        assert_eq!([1.0_f32, 2.0, 3.0].sum(), 6.0);
+        assert_eq!([[1.0_f64, 2.0], [3.0, 4.0]].sum(), 10.0);
+        assert_eq!([[1_u64, 2], [3, 4]].sum(), 10);
+    }
+
+    #[test]
+    fn lerp_endpoints_bitwise() {
+        let bits = |v: [f64; 3]| v.map(f64::to_bits);
+        let a = [-0.0_f64, f64::MAX, 0.1];
+        let b = [1.0_f64, -f64::MAX, -0.0];
+        assert_eq!(bits(a.lerp(b, 0.0)), bits(a));
+        assert_eq!(bits(a.lerp(b, 1.0)), bits(b));
+        assert_eq!((-0.0_f64).lerp(1.0, 0.0).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(1.0_f64.lerp(-0.0, 1.0).to_bits(), (-0.0_f64).to_bits());
+        let mut m = [a];
+        m.mut_lerp(&[b], 1.0);
+        assert_eq!(bits(m[0]), bits(b));
+    }
+
+    #[test]
+    fn mean() {
+        assert_eq!([2.0_f32, 4.0, 6.0].mean(), 4.0);
+        assert_eq!([[1.0_f64], [2.0], [6.0]].mean(), 3.0);
+        assert_eq!(<[[f32; 3]; 4]>::DIM, 12);
+        assert_eq!([[1.0_f64, 2.0, 3.0], [0.0, 0.0, 0.0]].mean(), 1.0);
+        assert_eq!([7_u32, 8, 9].mean(), 8);
+        assert!(([] as [f64; 0]).mean().is_nan());
+        assert!(([] as [[f32; 2]; 0]).mean().is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn mean_empty_integer() {
+        ([] as [i32; 0]).mean();
+    }
+
+    #[test]
+    fn product() {
+        assert_eq!([[1.0_f64, 2.0], [3.0, 4.0]].sum(), 10.0);
+        assert_eq!([[1.0_f64, 2.0], [3.0, 4.0]].product(), 24.0);
+        assert_eq!(([] as [f32; 0]).product(), 1.0);
+        assert_eq!([2_i64, -3].product(), -6);
+    }
+
+    #[test]
+    fn cross_properties() {
+        let (a, b) = ([1.0_f64, 2.0, 3.0], [-4.0, 0.5, 2.0]);
+        let c = a.cross(b);
+        assert_eq!(c.dot(a), 0.0);
+        assert_eq!(c.dot(b), 0.0);
+        assert_eq!(b.cross(a), c.neg());
+        assert_eq!([1.0_f32, 0.0, 0.0].cross([0.0, 1.0, 0.0]), [0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn perp() {
+        let (a, b) = ([3.0_f64, 1.0], [-1.0, 2.0]);
+        assert_eq!([1.0_f32, 0.0].perp(), [0.0, 1.0]);
+        assert_eq!(a.perp().norm2(), a.norm2());
+        assert_eq!(a.perp_dot(b), a.perp().dot(b));
+        assert!([1.0_f64, 0.0].perp_dot([0.0, 1.0]) > 0.0);
+        assert!([0.0_f64, 1.0].perp_dot([1.0, 0.0]) < 0.0);
+    }
+
+    #[test]
+    fn integer_normalized_and_checked() {
+        assert_eq!([3_i32, 4].dot([1, 2]), 11);
+        assert_eq!([-3_i32, 0, 7].normalized(), [-1, 0, 1]);
+        assert_eq!([[0_u32, 9]].normalized(), [[0, 1]]);
+        assert_eq!((-5_i64).normalized(), -1);
+        assert_eq!([3_u32, 4].checked_norm2(), Some(25));
+        assert_eq!([70_000_u32, 0].checked_norm2(), None);
+        assert_eq!([50_000_u32, 50_000].checked_norm2(), None);
+        assert_eq!([[i64::MAX], [1]].checked_dot([[1], [1]]), None);
+    }
+
+    #[test]
+    fn zero_one() {
+        assert_eq!(<[f64; 4]>::zero().norm2(), 0.0);
+        assert_eq!(<[f64; 3]>::one().sum(), 3.0);
+        assert_eq!(<[[i32; 2]; 2]>::one(), [[1; 2]; 2]);
+    }
+
+    #[test]
+    fn min_max_comp_nan() {
+        assert_eq!([1_i32, 4].max_comp([3, 2]), [3, 4]);
+        assert_eq!([f64::NAN, 1.0].min_comp([2.0, f64::NAN]), [2.0, 1.0]);
+        assert_eq!([f64::NAN, 1.0].max_comp([2.0, f64::NAN]), [2.0, 1.0]);
+        let a = [[1.0_f64, f64::NAN]; 3];
+        assert_eq!(a.max_comp([[0.0, 5.0]; 3]), [[1.0, 5.0]; 3]);
+    }
+
+    #[test]
+    fn cosine_similarity() {
+        assert_eq!([1e150_f64, 0.0].cosine_similarity([1e150, 0.0]), 1.0);
+        assert_eq!([1e150_f64, 0.0].cosine_similarity([-1e150, 0.0]), -1.0);
+        assert_eq!([1.0_f64, 0.0].cosine_similarity([0.0, 2.0]), 0.0);
+        assert_eq!([[0.1_f32], [0.7]].cosine_similarity([[0.1], [0.7]]), 1.0);
+        assert!([0.0_f64, 0.0].cosine_similarity([1.0, 0.0]).is_nan());
+        assert!(0.0_f64.cosine_similarity(1.0).is_nan());
+        assert!([0.0_f64, 0.0].angle_between([1.0, 0.0]).is_nan());
+    }
+
+    #[test]
+    fn cross_nested() {
+        let a = [[1.0_f32, 2.0], [0.0, 1.0], [0.0, 0.0]];
+        let b = [[0.0_f32, 0.0], [1.0, 0.0], [0.0, 3.0]];
+        let c = a.cross(b);
+        assert_eq!(c, [[0.0, 3.0], [0.0, -6.0], [1.0, 0.0]]);
+        assert_eq!(c.dot(a), 0.0);
+    }
+
+    #[test]
+    fn cross_integer() {
+        assert_eq!([2_i32, 0, 0].cross([0, 3, 0]), [0, 0, 6]);
+    }
+
+    #[test]
+    fn min_max_element() {
+        assert_eq!([3.0_f32, -1.0, 4.0, 1.5].min_element(), -1.0);
+        assert_eq!([3.0_f32, -1.0, 4.0, 1.5].max_element(), 4.0);
+        let m = [[2.0_f32, 7.0], [-3.0, 0.0], [5.0, 1.0]];
+        assert_eq!((m.min_element(), m.max_element()), (-3.0, 7.0));
+        assert_eq!(([] as [f64; 0]).min_element(), f64::INFINITY);
+        assert_eq!(([] as [f64; 0]).max_element(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn project_reject_sum() {
+        let (v, u) = ([0.3_f64, -1.7, 2.2], [1.1, 0.4, -0.9]);
+        let sum = v.project_onto(u).add(v.reject_from(u));
+        assert!(sum.distance(v) < 1e-15);
+        assert!(v.reject_from(u).dot(u).abs() < 1e-15);
+    }
+
+    #[test]
+    fn angle_right() {
+        use core::f32::consts::FRAC_PI_2;
+
+        assert_eq!([1.0_f32, 0.0].angle_between([0.0, 1.0]), FRAC_PI_2);
+        assert_eq!([[1.0_f32], [0.0]].angle_between([[0.0], [5.0]]), FRAC_PI_2);
+    }
+
+    #[test]
+    fn reflect_refract() {
+        let n = [0.0_f64, 1.0];
+        let v = [0.6_f64, -0.8];
+        assert_eq!(v.reflect(n).reflect(n), v);
+        assert_eq!(v.refract(n, 1.0), Some(v));
+        assert_eq!([0.8_f64, -0.6].refract(n, 1.5), None);
+        let r = [0.6_f64, -0.8].refract(n, 0.5).unwrap();
+        assert!((r.norm() - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn clamp_vec() {
+        assert_eq!([0.0_f64, 5.0].clamp_vec([1.0, 1.0], [2.0, 2.0]), [1.0, 2.0]);
+        let mut m = [[0_i32, 9], [4, -4]];
+        m.mut_clamp_vec(&[[1, 1], [0, 0]], &[[2, 2], [3, 3]]);
+        assert_eq!(m, [[1, 2], [3, 0]]);
+    }
+
+    #[test]
+    fn project_axis() {
+        assert_eq!([3.0_f64, 4.0].project_onto([1.0, 0.0]), [3.0, 0.0]);
+        assert_eq!([3.0_f64, 4.0].reject_from([1.0, 0.0]), [0.0, 4.0]);
+    }
+
+    #[test]
+    fn recip() {
+        assert_eq!([2.0_f64, 4.0].recip(), [0.5, 0.25]);
+        assert_eq!([0.0_f32, -0.0].recip(), [f32::INFINITY, f32::NEG_INFINITY]);
+        let mut v = [[4.0_f32]];
+        v.mut_recip();
+        assert_eq!(v, [[0.25]]);
+    }
+
+    #[test]
+    fn map_scalar() {
+        assert_eq!([1.0_f64, 2.0, 3.0].map_scalar(|x| x * x), [1.0, 4.0, 9.0]);
+        let mut n = 0.0;
+        let m = [[0.0_f32; 2]; 2].map_scalar(|_| {
+            n += 1.0;
+            n
+        });
+        assert_eq!(m, [[1.0, 2.0], [3.0, 4.0]]);
+    }
+
+    #[test]
+    fn clamp_vec_nested() {
+        let (lo, hi) = ([[0.0_f64, -1.0]; 2], [[1.0, 1.0]; 2]);
+        assert_eq!(
+            [[2.0, -2.0], [0.5, 0.0]].clamp_vec(lo, hi),
+            [[1.0, -1.0], [0.5, 0.0]]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_vec_inverted_bounds() {
+        [0.5_f64].clamp_vec([1.0], [0.0]);
+    }
+
+    #[test]
+    fn map_rows_and_leaves() {
+        let m = [[1.0_f64, 2.0], [3.0, 4.0]];
+        assert_eq!(m.map(|r| r.scal_mul(2.0)), m.map_scalar(|x| x * 2.0));
+        assert_eq!(m.map(|r| r.sum()), [3.0, 7.0]);
+        assert_eq!([0.0_f64].map_scalar(f64::sin), [0.0]);
+    }
+
+    #[test]
+    fn fold_scalar() {
+        let m = [[1.0_f64, 2.0], [3.0, 4.0]];
+        assert_eq!(m.fold_scalar(0.0, |a, x| a + x), m.sum());
+        assert_eq!(m.fold_scalar(0.0, |a, x| a * 10.0 + x), 1234.0);
+    }
+
+    #[test]
+    fn rounding_and_sign() {
+        let bits = |v: [f64; 2]| v.map(f64::to_bits);
+        let z = [-0.0_f64, 0.0];
+        assert_eq!(z.signum(), [-1.0, 1.0]);
+        assert_eq!(bits(z.abs()), bits([0.0, 0.0]));
+        assert_eq!(bits(z.floor()), bits(z));
+        assert_eq!(bits(z.ceil()), bits(z));
+        assert_eq!(bits(z.round()), bits(z));
+        assert_eq!([-1.5_f64, 2.5].floor(), [-2.0, 2.0]);
+        assert_eq!([-1.5_f64, 2.5].ceil(), [-1.0, 3.0]);
+        assert_eq!([-1.5_f64, 2.5].round(), [-2.0, 3.0]);
+        let nan = [f64::NAN];
+        for v in [
+            nan.signum(),
+            nan.abs(),
+            nan.floor(),
+            nan.ceil(),
+            nan.round(),
+        ] {
+            assert!(v[0].is_nan());
+        }
+        let mut v = [[-0.4_f32, 0.6]];
+        v.mut_round();
+        assert_eq!(
+            v.map(|r| r.map(f32::to_bits)),
+            [[(-0.0_f32).to_bits(), 1.0_f32.to_bits()]]
+        );
+    }
+
+    #[test]
+    fn zip_with_scalar() {
+        assert_eq!(
+            [2.0_f32, 4.0].zip_with_scalar([1.0, 2.0], |a, b| a / b),
+            [2.0, 2.0]
+        );
+        let (a, b) = ([[1.0_f64, 4.0]; 2], [[9.0, 1.0]; 2]);
+        assert_eq!(a.zip_with_scalar(b, |x, y| (x * y).sqrt()), [[3.0, 2.0]; 2]);
+    }
+
+    #[test]
+    fn scale_add() {
+        let (y, x) = ([1.0_f64, -2.0, 0.5], [4.0, 0.25, -8.0]);
+        assert_eq!(y.scale_add(x, 0.5), y.add(x.scal_mul(0.5)));
+        let mut z = [y];
+        z.mut_scale_add(&[x], 0.5);
+        assert_eq!(z, [[3.0, -1.875, -3.5]]);
+    }
+
+    #[test]
+    fn neg_integers() {
+        assert_eq!([1_i32, -2].neg(), [-1, 2]);
+        let v = [[1.5_f64, -2.0], [0.25, 3.0]];
+        assert_eq!(v.add(v.neg()), <[[f64; 2]; 2]>::zero());
+        let w = [[3_i64, -4]; 3];
+        assert_eq!(w.add(w.neg()), [[0; 2]; 3]);
+        let mut w = w;
+        w.mut_neg();
+        assert_eq!(w, [[-3, 4]; 3]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fold_scalar_collect() {
+        use alloc::vec::Vec;
+
+        assert_eq!([1.0_f32, 2.0, 3.0].fold_scalar(0.0, |a, x| a + x), 6.0);
+        let leaves = [[1.0_f64, 2.0], [3.0, 4.0]].fold_scalar(Vec::new(), |mut a, x| {
+            a.push(x);
+            a
+        });
+        assert_eq!(leaves, [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn provided_geometry_matches_on_scalars_and_arrays() {
+        assert_eq!(
+            2.0_f64.cosine_similarity(-3.0),
+            [2.0_f64].cosine_similarity([-3.0])
+        );
+        assert_eq!(
+            2.0_f64.angle_between(-3.0),
+            [[2.0_f64]].angle_between([[-3.0]])
+        );
+        assert_eq!((-1.0_f64).refract(1.0, 1.0), Some(-1.0));
+    }
+
+    #[test]
+    fn scal_div_is_exact_division() {
+        let v = [5.0_f64, 7.0, 10.0];
+        assert_eq!(v.scal_div(3.0), [5.0 / 3.0, 7.0 / 3.0, 10.0 / 3.0]);
+        assert_ne!(v.scal_div(3.0), v.scal_mul(3.0_f64.recip()));
+        assert_eq!([1.0_f64].scal_add(0.5).scal_sub(2.0), [-0.5]);
+        let mut m = [[5.0_f64; 2]];
+        m.mut_scal_div(3.0);
+        m.mut_scal_add(1.0);
+        m.mut_scal_sub(1.0);
+        assert_eq!(m[0][0].to_bits(), (5.0_f64 / 3.0 + 1.0 - 1.0).to_bits());
+    }
+
+    #[test]
+    fn all_any_short_circuit() {
+        let m = [[1.0_f64, -2.0], [3.0, 4.0]];
+        let mut calls = 0;
+        assert!(!m.all(|x| {
+            calls += 1;
+            x > 0.0
+        }));
+        assert_eq!(calls, 2);
+        calls = 0;
+        assert!(m.any(|x| {
+            calls += 1;
+            x > 2.0
+        }));
+        assert_eq!(calls, 3);
+        assert!(m.all(f64::is_finite));
+        assert!(!m.any(f64::is_nan));
+    }
+
+    #[test]
+    fn integer_lattice() {
+        assert_eq!([1, 2, 3].dot([4, 5, 6]), 32);
+        let m = [[1_i32, 2, 3], [4, 5, 6], [7, 8, 9]];
+        assert_eq!(m.add(m).sub(m), m);
+        assert_eq!(m.dot(<[[i32; 3]; 3]>::one()), 45);
+        assert_eq!(m.scal_mul(-1).clamp_scalar(-5, 0)[2], [-5; 3]);
+    }
+
+    #[test]
+    fn try_normalized_zero_and_unit() {
+        assert_eq!([0.0_f32; 3].try_normalized(), None);
+        assert_eq!([0.0_f32, 5.0, 0.0].try_normalized(), Some([0.0, 1.0, 0.0]));
+    }
+
+    #[test]
+    fn floor_round_ties_even() {
+        assert_eq!([1.4_f32, 1.6, -1.4, -1.6].floor(), [1.0, 1.0, -2.0, -2.0]);
+        assert_eq!(
+            [0.5_f64, 1.5, 2.5, -2.5].round_ties_even(),
+            [0.0, 2.0, 2.0, -2.0]
+        );
+        assert_eq!([0.5_f64, 1.5, 2.5, -2.5].round(), [1.0, 2.0, 3.0, -3.0]);
+    }
+
+    #[test]
+    fn fma_single_rounding() {
+        let a = 1.0 + f64::EPSILON;
+        let b = -(1.0 + 2.0 * f64::EPSILON);
+        assert_eq!(a * a + b, 0.0);
+        assert_eq!([a].fma([a], [b]), [f64::EPSILON * f64::EPSILON]);
+        let mut m = [[a; 2]];
+        m.mut_fma(&[[a; 2]], &[[b; 2]]);
+        assert_eq!(m, [[f64::EPSILON * f64::EPSILON; 2]]);
+    }
+
+    #[test]
+    fn normalize_or_zero() {
+        assert_eq!([0.0_f64, 0.0].normalize_or_zero(), [0.0, 0.0]);
+        assert_eq!([0.0_f64, -2.0].normalize_or_zero(), [0.0, -1.0]);
+        let mut v = [[0.0_f32; 2]; 2];
+        v.mut_normalize_or_zero();
+        assert_eq!(v, [[0.0; 2]; 2]);
+        let mut v = [f32::NAN, 1.0];
+        v.mut_normalize_or_zero();
+        assert_eq!(v, [0.0, 0.0]);
+    }
+
+    #[test]
+    fn transcendental() {
+        assert_eq!([4.0_f64, 9.0, 16.0].sqrt(), [2.0, 3.0, 4.0]);
+        assert!((-1.0_f64).sqrt().is_nan());
+        assert_eq!([0.0_f64].ln(), [f64::NEG_INFINITY]);
+        let v = [[-1.5_f64, 0.25], [2.0, 7.0]];
+        assert!(v.exp().ln().distance(v) < 1e-14);
+        assert_eq!([3.0_f64, -1.0].exp2().log2(), [3.0, -1.0]);
+    }
+
+    #[test]
+    fn constructors() {
+        let v = [[1.0_f64, -2.0, 0.5]; 2];
+        assert_eq!(<[[f64; 3]; 2]>::zero().add(v), v);
+        assert_eq!(<[[f32; 2]; 2]>::splat(7.0), [[7.0; 2]; 2]);
+        let m = <[[[u64; 2]; 3]; 2]>::from_fn(|i| i as u64);
+        assert_eq!(m[0], [[0, 1], [2, 3], [4, 5]]);
+        assert_eq!(m[1], [[6, 7], [8, 9], [10, 11]]);
+    }
+
+    #[test]
+    fn trigonometry() {
+        use core::f32::consts::FRAC_PI_2;
+
+        let s = [0.0_f32, FRAC_PI_2].sin();
+        assert!(s.distance([0.0, 1.0]) <= f32::EPSILON * 4.0);
+        assert!([2.0_f64].asin()[0].is_nan());
+        assert_eq!(
+            [1.0_f64, -1.0].atan2([-1.0, -1.0]),
+            [1.0_f64.atan2(-1.0), (-1.0_f64).atan2(-1.0)]
+        );
+        let v = [[0.3_f64, -0.2]];
+        assert!(v.cos().acos().distance(v.abs()) < 1e-15);
+        assert!(v.tan().atan().distance(v) < 1e-15);
+    }
+
+    #[test]
+    fn normalized_negative_scalar() {
+        assert_eq!((-3.0_f32).normalized(), -1.0);
+        let mut x = -0.25_f64;
+        x.mut_normalized();
+        assert_eq!(x, -1.0);
+    }
+
+    #[test]
+    fn unit_basis() {
+        type M = [[f64; 3]; 2];
+        for i in 0..M::DIM {
+            for j in 0..M::DIM {
+                assert_eq!(M::unit(i).dot(M::unit(j)), (i == j) as u8 as f64);
+            }
+        }
+        assert_eq!(M::unit(4), [[0.0; 3], [0.0, 1.0, 0.0]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn unit_out_of_range() {
+        <[[f64; 3]; 2]>::unit(6);
+    }
+
+    #[test]
+    fn unit_integer() {
+        assert_eq!(<[i32; 3]>::unit(1), [0, 1, 0]);
+    }
+
+    #[test]
+    fn clamp_length() {
+        assert_eq!([3.0_f64, 4.0].clamp_length_max(2.5), [1.5, 2.0]);
+        assert_eq!([3.0_f64, 4.0].clamp_length_max(10.0), [3.0, 4.0]);
+        assert_eq!([0.0_f64, 0.0].clamp_length_max(1.0), [0.0, 0.0]);
+        assert_eq!([3.0_f64, 4.0].clamp_length_min(10.0), [6.0, 8.0]);
+        assert_eq!([3.0_f64, 4.0].clamp_length_min(1.0), [3.0, 4.0]);
+        let mut v = [[3.0_f64], [4.0]];
+        v.mut_clamp_length_max(2.5);
+        assert_eq!(v, [[1.5], [2.0]]);
+        v.mut_clamp_length_min(5.0);
+        assert_eq!(v, [[3.0], [4.0]]);
+    }
+
+    #[test]
+    fn clamp_length_large_and_small() {
+        let close = |v: [f64; 2], w: [f64; 2]| v.distance(w) <= 1e-15;
+        let h = core::f64::consts::FRAC_1_SQRT_2;
+        assert!(close([1e200_f64, 1e200].clamp_length_max(1.0), [h, h]));
+        assert_eq!(
+            [1e200_f64, 1e200].clamp_length_max(f64::MAX),
+            [1e200, 1e200]
+        );
+        assert_eq!([1e-300_f64, 0.0].clamp_length_min(1.0), [1.0, 0.0]);
+        assert!(close([1e-200_f64, 1e-200].clamp_length_min(1.0), [h, h]));
+        let mut v = [[1e300_f64], [0.0]];
+        v.mut_clamp_length_max(2.0);
+        assert_eq!(v, [[2.0], [0.0]]);
+        assert!([f64::NAN, 1.0].clamp_length_max(1.0)[0].is_nan());
+    }
+
+    #[test]
+    fn powers() {
+        assert_eq!([2.0_f64, 3.0].powi(3), [8.0, 27.0]);
+        assert_eq!([4.0_f64, 9.0].powf([0.5, 2.0]), [2.0, 81.0]);
+        assert_eq!(
+            [[1.5_f64, -3.0]].powf_scalar(2.0),
+            [[1.5_f64, -3.0]].mul([[1.5, -3.0]])
+        );
+    }
+
+    #[test]
+    fn mean_nested_count() {
+        let m = [[1.0_f64, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]];
+        assert_eq!(m.mean(), 4.5);
+        assert_eq!(m.sum() / 2.0, 18.0);
+        assert_eq!(7.0_f32.mean(), 7.0);
+        assert_eq!(7.0_f32.product(), 7.0);
+    }
+
+    #[test]
+    fn recip_f32() {
+        assert_eq!([2.0_f32, 4.0, 8.0].recip(), [0.5, 0.25, 0.125]);
+    }
+
+    #[test]
+    fn with_length() {
+        assert_eq!([0.0_f64, 2.0].with_length(5.0), [0.0, 5.0]);
+        assert_eq!([0.0_f64, 0.0].with_length(5.0), [0.0, 0.0]);
+        let mut v = [[0.0_f32], [-4.0]];
+        v.mut_set_length(2.0);
+        assert_eq!(v, [[0.0], [-2.0]]);
+    }
+
+    #[test]
+    fn argmin_argmax() {
+        let m = [
+            [4.0_f64, 2.0, 9.0, 1.5],
+            [3.0, -7.0, 0.0, 12.0],
+            [-7.0, 5.0, 12.0, 8.0],
+        ];
+        assert_eq!(m.argmin(), 5);
+        assert_eq!(m.argmax(), 7);
+        assert_eq!((m.min_element(), m.max_element()), (-7.0, 12.0));
+        let n = [[f64::NAN, 3.0], [1.0, f64::NAN]];
+        assert_eq!((n.argmin(), n.argmax()), (2, 1));
+        assert_eq!((n.min_element(), n.max_element()), (1.0, 3.0));
+        assert_eq!([f64::NAN; 2].argmax(), 0);
+        assert_eq!(([] as [f32; 0]).argmin(), 0);
+    }
+
+    #[test]
+    fn move_towards() {
+        assert_eq!([0.0_f64, 0.0].move_towards([10.0, 0.0], 3.0), [3.0, 0.0]);
+        let target = [0.1_f64, 0.7];
+        assert_eq!([0.0_f64, 0.5].move_towards(target, 1.0), target);
+        assert_eq!(target.move_towards(target, 0.0), target);
+    }
+
+    #[test]
+    fn signum_nan_and_integers() {
+        let s = [-3.0_f32, 0.0, 5.0, f32::NAN].signum();
+        assert_eq!(s[..3], [-1.0, 1.0, 1.0]);
+        assert!(s[3].is_nan());
+        assert_eq!([-3_i32, 0, 5].signum(), [-1, 0, 1]);
+        assert_eq!([[0_u64, 5]].signum(), [[0, 1]]);
+    }
+
+    #[test]
+    fn map_zip_closures() {
+        let m = [[1.0_f64, -2.0], [3.0, 0.5]];
+        assert_eq!(m.map_scalar(|x| x * x), [[1.0, 4.0], [9.0, 0.25]]);
+        let (y, x) = ([[1.0_f64, -1.0]; 2], [[-1.0, -1.0]; 2]);
+        let a = y.zip_with_scalar(x, f64::atan2);
+        assert_eq!(a, [[1.0_f64.atan2(-1.0), (-1.0_f64).atan2(-1.0)]; 2]);
+    }
+
+    #[test]
+    fn is_normalized() {
+        assert!([1.0_f64, 0.0, 0.0].is_unit());
+        assert!(![2.0_f64, 0.0, 0.0].is_unit());
+        assert!([0.6_f32, 0.8].is_unit());
+        assert!([1.0_f64, 0.01].is_normalized(1e-3));
+        assert!(![1.0_f64, 0.1].is_normalized(1e-3));
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!([0.0_f64, 0.0].midpoint([4.0, 6.0]), [2.0, 3.0]);
+        assert_eq!([0_i32, 0].midpoint([4, 7]), [2, 3]);
+        assert_eq!([-3_i32, 3].midpoint([0, 0]), [-1, 1]);
+        assert_eq!(
+            [i32::MAX, i32::MAX].midpoint([i32::MAX, 1]),
+            [i32::MAX, i32::MAX / 2 + 1]
+        );
+        assert_eq!([i64::MIN].midpoint([i64::MAX]), [0]);
+        assert_eq!([[u64::MAX]].midpoint([[u64::MAX - 2]]), [[u64::MAX - 1]]);
+        assert_eq!([f64::MAX].midpoint([f64::MAX]), [f64::MAX]);
+        assert_eq!([f32::MIN, 1.0].midpoint([f32::MAX, 3.0]), [0.0, 2.0]);
+        assert_eq!(
+            [0.0_f32, 2.0].lerp([1.0, 4.0], 0.5),
+            [0.0, 2.0].midpoint([1.0, 4.0])
+        );
+    }
+
+    #[test]
+    fn for_each_scalar_histogram() {
+        let m = [[0.1_f64, 0.6, 0.9], [0.4, 0.55, 0.05]];
+        let mut histogram = [0_u32; 2];
+        m.for_each_scalar(|x| histogram[(x >= 0.5) as usize] += 1);
+        assert_eq!(histogram, [3, 3]);
+        assert_eq!(m.fold_scalar(0.0, |a, x| a + x * x), m.dot(m));
+    }
+
+    #[test]
+    fn norm_p_two() {
+        let v = [[0.3_f64, -1.2], [2.5, 0.7]];
+        assert!((v.norm_p(2.0) - v.norm()).abs() <= 4.0 * f64::EPSILON * v.norm());
+    }
+
+    #[test]
+    fn normalized_scalar_regressions() {
+        assert_eq!((-5.0_f32).normalized(), -1.0);
+        assert_eq!(5.0_f64.normalized(), 1.0);
+        assert!(0.0_f64.normalized().is_nan());
+        assert!(f64::NAN.normalized().is_nan());
+        assert_eq!(f32::NEG_INFINITY.try_normalized(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_matches_array() {
+        use alloc::vec;
+
+        let a = [[1.0_f64, 2.0, 2.0], [0.0, 3.0, 4.0]];
+        let b = [[2.0_f64, 0.5, 1.0], [1.0, -1.0, 0.0]];
+        let (va, vb) = (a.to_vec(), b.to_vec());
+        assert_eq!(va.clone().dot(vb.clone()), a.dot(b));
+        assert_eq!(va.clone().add(vb.clone()), a.add(b).to_vec());
+        assert_eq!(va.clone().mean(), a.mean());
+        assert_eq!(va.clone().norm2(), a.norm2());
+        assert_eq!(va.clone().distance2(vb.clone()), a.distance2(b));
+        assert_eq!(va.clone().normalized(), a.normalized().to_vec());
+        assert_eq!(va.clone().reflect(vb.clone()), a.reflect(b).to_vec());
+        assert_eq!(
+            va.clone().project_onto(vb.clone()),
+            a.project_onto(b).to_vec()
+        );
+        assert_eq!(
+            va.clone().clamp_length_max(1.0),
+            a.clamp_length_max(1.0).to_vec()
+        );
+        assert_eq!(
+            va.clone().move_towards(vb, 0.5),
+            a.move_towards(b, 0.5).to_vec()
+        );
+        assert!(vec![0.6_f64, 0.8].is_unit());
+        assert!(!va.is_unit());
+        assert!(vec![[0.0_f64; 3]; 0].mean().is_nan());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "mismatched lengths")]
+    fn vec_length_mismatch() {
+        use alloc::vec;
+
+        vec![1.0_f64, 2.0].dot(vec![1.0, 2.0, 3.0]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "mismatched lengths")]
+    fn vec_zip_with_scalar_length_mismatch() {
+        use alloc::vec;
+
+        vec![1.0_f64, 2.0].zip_with_scalar(vec![1.0], f64::max);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn try_normalized_vec() {
+        use alloc::vec;
+
+        assert_eq!(vec![0.0_f64; 3].try_normalized(), None);
+        assert_eq!(vec![1e200_f64, 0.0].try_normalized(), Some(vec![1.0, 0.0]));
+        assert_eq!(vec![1e-310_f64, 0.0].normalize_or_zero(), vec![1.0, 0.0]);
+        assert_eq!(vec![f64::NAN, 0.0].normalize_or_zero(), vec![0.0, 0.0]);
+        let mut v = vec![[1e200_f64], [0.0]];
+        assert!(v.mut_try_normalized());
+        assert_eq!(v, vec![[1.0], [0.0]]);
+    }
+
+    #[test]
+    fn vector_operators() {
+        let (a, b) = ([1.0_f64, -2.0, 4.0], [0.5_f64, 4.0, -2.0]);
+        let (va, vb) = (Vector::from(a), Vector::from(b));
+        assert_eq!(<[f64; 3]>::from(va + vb), a.add(b));
+        assert_eq!((va - vb).0, a.sub(b));
+        assert_eq!((va * vb).0, a.mul(b));
+        assert_eq!((va / vb).0, a.div(b));
+        assert_eq!((-va).0, a.neg());
+        assert_eq!((va * 3.0).0, a.scal_mul(3.0));
+        assert_eq!((va / 4.0).0, a.scal_div(4.0));
+        assert_eq!(((va + vb) * 0.5).0, a.add(b).scal_mul(0.5));
+        assert_eq!(va.dot(*vb), a.dot(b));
+        let mut vc = va;
+        vc[1] = 7.0;
+        assert_eq!(vc.0, [1.0, 7.0, 4.0]);
+        assert_eq!((Vector([7_i32, -8]) / 2).0, [3, -4]);
+    }
+
+    #[test]
+    fn try_normalized_degenerate() {
+        assert_eq!([0.0_f64; 4].try_normalized(), None);
+        assert_eq!([[0.0_f64; 2]; 2].try_normalized(), None);
+        assert_eq!([1.0_f64, f64::NAN].try_normalized(), None);
+        assert_eq!([f64::INFINITY, 0.0].try_normalized(), None);
+        assert_eq!(0.0_f64.try_normalized(), None);
+        assert!([0.0_f64; 4].normalized().iter().all(|x| x.is_nan()));
+        let mut v = [0.0_f64; 4];
+        assert!(!v.mut_try_normalized());
+        assert_eq!(v, [0.0; 4]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_clone_defaults() {
+        use alloc::boxed::Box;
+
+        let (a, b) = ([3.0_f64, 0.0, 4.0], [0.0_f64, 2.0, 0.0]);
+        let (ba, bb) = (Box::new(a), Box::new(b));
+        assert_eq!(ba.clone().norm2(), a.norm2());
+        assert_eq!(ba.clone().distance2(bb.clone()), a.distance2(b));
+        assert!(!ba.clone().is_unit());
+        assert!(Box::new([0.6_f64, 0.8, 0.0]).is_unit());
+        assert_eq!(*ba.clone().project_onto(bb.clone()), a.project_onto(b));
+        assert_eq!(*ba.clone().reflect(bb.clone()), a.reflect(b));
+        assert_eq!(*ba.clone().clamp_length_max(1.0), a.clamp_length_max(1.0));
+        assert_eq!(*ba.clone().clamp_length_min(10.0), a.clamp_length_min(10.0));
+        assert_eq!(*ba.move_towards(bb, 1.0), a.move_towards(b, 1.0));
+    }
+
+    /// 16 MiB, several times the default test-thread stack, so any copy of the array onto the
+    /// stack would overflow.
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn boxed_large_stays_on_heap() {
+        use alloc::boxed::Box;
+
+        type Big = Box<[[f64; 8]; 262144]>;
+        let mut a = Big::splat(1.0);
+        let b = Big::zero().scal_add(2.0);
+        a.mut_add(&b);
+        a.mut_scal_mul(0.5);
+        let c = a.add(b);
+        assert_eq!(c[262143], [3.5; 8]);
+        assert_eq!(c.clone().sum(), 3.5 * 8.0 * 262144.0);
+        assert_eq!(c.norm2(), 3.5 * 3.5 * 8.0 * 262144.0);
+    }
+
+    #[test]
+    fn norm_l1_cases() {
+        assert_eq!([-1.0_f32, 2.0, -3.0].norm_l1(), 6.0);
+        assert_eq!((-2.5_f64).norm_l1(), 2.5);
+        assert_eq!(([] as [f64; 0]).norm_l1(), 0.0);
+        assert_eq!([f64::NEG_INFINITY, 1.0].norm_l1(), f64::INFINITY);
+        assert!([f64::NAN, 1.0].norm_l1().is_nan());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn norm_l1_vec_and_box() {
+        use alloc::{boxed::Box, vec};
+
+        let a = [[1.0_f64, -2.0], [-3.0, 4.0]];
+        assert_eq!(vec![[1.0_f64, -2.0], [-3.0, 4.0]].norm_l1(), a.norm_l1());
+        assert_eq!(Box::new(a).norm_l1(), 10.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn vector_serde_json() {
+        let v = Vector([1.5_f64, -2.0, 1e-300]);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1.5,-2.0,1e-300]");
+        assert_eq!(serde_json::from_str::<Vector<f64, 3>>(&json).unwrap(), v);
+
+        let nested = Vector([Vector([1.0_f32, 2.0]), Vector([3.0, 4.0])]);
+        let json = serde_json::to_string(&nested).unwrap();
+        assert_eq!(json, "[[1.0,2.0],[3.0,4.0]]");
+        assert_eq!(
+            serde_json::from_str::<Vector<Vector<f32, 2>, 2>>(&json).unwrap(),
+            nested
+        );
+
+        assert!(serde_json::from_str::<Vector<f64, 3>>("[1.0,2.0]").is_err());
+        assert!(serde_json::from_str::<Vector<f64, 3>>("[1.0,2.0,3.0,4.0]").is_err());
+    }
+
+    /// Deterministic leaves in `[-1, 1)` for comparing the parallel variants to the serial ones.
+    #[cfg(feature = "rayon")]
+    fn par_input(n: usize, seed: u64) -> alloc::vec::Vec<[f64; 8]> {
+        let mut s = seed;
+        (0..n)
+            .map(|_| {
+                <[f64; 8]>::from_fn(|_| {
+                    s = s
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    (s >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+                })
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_reductions_match_serial() {
+        let (x, y) = (par_input(32768, 1), par_input(32768, 2));
+        let abs_dot = x
+            .iter()
+            .zip(&y)
+            .map(|(v, w)| v.abs().dot(w.abs()))
+            .sum::<f64>();
+        assert!((x.par_dot(&y) - x.clone().dot(y.clone())).abs() <= 1e-12 * abs_dot);
+        assert!((x.par_sum() - x.clone().sum()).abs() <= 1e-12 * x.clone().norm_l1());
+        assert!((x.par_norm2() - x.clone().norm2()).abs() <= 1e-12 * x.clone().norm2());
+        assert!((x.par_norm() - x.clone().norm()).abs() <= 1e-12 * x.clone().norm());
+        let d = x.clone().distance2(y.clone());
+        assert!((x.par_distance2(&y) - d).abs() <= 1e-12 * d);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_elementwise_is_exact() {
+        let (x, y) = (par_input(32768, 3), par_input(32768, 4));
+        let mut p = x.clone();
+        p.mut_par_add(&y);
+        assert_eq!(p, x.clone().add(y.clone()));
+        p.mut_par_sub(&y);
+        let mut s = x.clone().add(y.clone());
+        s.mut_sub(&y);
+        assert_eq!(p, s);
+        p.mut_par_mul(&y);
+        s.mut_mul(&y);
+        assert_eq!(p, s);
+        p.mut_par_div(&y);
+        s.mut_div(&y);
+        assert_eq!(p, s);
+        p.mut_par_scal_mul(3.0);
+        p.mut_par_scal_add(-1.0);
+        assert_eq!(p, s.scal_mul(3.0).scal_add(-1.0));
+        let mut p = x.clone();
+        p.mut_par_scale_add(&y, 0.25);
+        assert_eq!(p, x.clone().scale_add(y.clone(), 0.25));
+        let mut p = x.clone();
+        p.mut_par_lerp(&y, 0.75);
+        assert_eq!(p, x.lerp(y, 0.75));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_zero_dimension() {
+        let x = [[0.0_f64; 0]; 16];
+        assert_eq!(x.par_dot(&x), 0.0);
+        assert_eq!(x.par_sum(), 0.0);
+    }
+
+    #[test]
+    fn norm_inf_bounds() {
+        assert_eq!([3.0_f32, -5.0, 2.0].norm_inf(), 5.0);
+        assert_eq!((-4.0_f64).norm_inf(), 4.0);
+        let mut s = 7_u64;
+        for _ in 0..256 {
+            let v = <[[f64; 3]; 4]>::from_fn(|_| {
+                s = s
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (s >> 11) as f64 / (1u64 << 40) as f64 - 4096.0
+            });
+            let (inf, l1, l2) = (v.norm_inf(), v.norm_l1(), v.norm());
+            assert!(inf <= l1, "{v:?}");
+            assert!(l1 <= f64::sqrt(12.0) * l2 * (1.0 + 1e-15), "{v:?}");
+        }
+    }
+
+    /// Leaves in `[-1, 1)` with a few bits of mantissa, so that short dot products are exact.
+    #[cfg(feature = "simd")]
+    fn simd_input<const N: usize>(seed: u64) -> [f32; N] {
+        let mut s = seed;
+        <[f32; N]>::from_fn(|_| {
+            s = s
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((s >> 56) as f32 - 128.0) / 128.0
+        })
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_elementwise_matches_scalar() {
+        fn check<const N: usize>() {
+            let (a, b) = (simd_input::<N>(1), simd_input::<N>(2));
+            assert_eq!(a.add(b), a.zip_with_scalar(b, |x, y| x + y));
+            assert_eq!(a.mul(b), a.zip_with_scalar(b, |x, y| x * y));
+            assert_eq!(a.scal_mul(0.3), a.map_scalar(|x| x * 0.3));
+            let mut m = a;
+            m.mut_add(&b);
+            assert_eq!(m, a.add(b));
+            let mut m = [a, b];
+            m.mut_mul(&[b, a]);
+            assert_eq!(m, [a.mul(b), b.mul(a)]);
+            m.mut_scal_mul(-2.0);
+            assert_eq!(
+                m,
+                [a.mul(b).map_scalar(|x| x * -2.0), b.mul(a).scal_mul(-2.0)]
+            );
+        }
+        check::<3>();
+        check::<7>();
+        check::<64>();
+        check::<4099>();
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_dot_matches_scalar() {
+        fn serial<const N: usize>(a: [f32; N], b: [f32; N]) -> f32 {
+            a.iter().zip(&b).fold(0.0, |s, (x, y)| s + x * y)
+        }
+        // No full chunk: the tail loop is the serial fold.
+        let (a, b) = (simd_input::<7>(3), simd_input::<7>(4));
+        assert_eq!(a.dot(b).to_bits(), serial(a, b).to_bits());
+        // Few enough bits that every partial sum is exact, whatever the order.
+        let (a, b) = (simd_input::<64>(5), simd_input::<64>(6));
+        assert_eq!(a.dot(b), serial(a, b));
+        // Otherwise the lanes reorder the sum, within the usual `n * eps` bound.
+        let (a, b) = (
+            simd_input::<4099>(7).map(|x| x * 1.1),
+            simd_input::<4099>(8),
+        );
+        let d = a.dot(b);
+        assert!((d - serial(a, b)).abs() <= a.abs().dot(b.abs()) * 4099.0 * f32::EPSILON);
+    }
+
+    #[cfg(all(feature = "simd", feature = "alloc"))]
+    #[test]
+    fn simd_vec_dot_matches_array() {
+        let (a, b) = (simd_input::<64>(9), simd_input::<64>(10));
+        let (a, b) = (a.map(|x| x * 1.1), b.map(|x| x * 0.7));
+        assert_eq!(a.to_vec().dot(b.to_vec()).to_bits(), a.dot(b).to_bits());
+        let (x, y) = ([a, b, a], [b, b, a]);
+        assert_eq!(x.to_vec().dot(y.to_vec()).to_bits(), x.dot(y).to_bits());
+    }
+
+    #[test]
+    fn dot_matches_serial_fold() {
+        fn serial(x: &[f64], y: &[f64]) -> f64 {
+            x.iter().zip(y).fold(0.0, |s, (a, b)| s + a * b)
+        }
+        let mut s = 11_u64;
+        let mut next = || {
+            s = s
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (s >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+        };
+        let x = <[[f64; 64]; 4]>::from_fn(|_| next());
+        let y = <[[f64; 64]; 4]>::from_fn(|_| next());
+        let (xs, ys) = (x.as_flattened(), y.as_flattened());
+        let bound = x.abs().dot(y.abs()) * 256.0 * f64::EPSILON;
+        assert!((x.dot(y) - serial(xs, ys)).abs() <= bound);
+        for (a, b) in x.iter().zip(&y) {
+            let bound = a.abs().dot(b.abs()) * 64.0 * f64::EPSILON;
+            assert!((a.dot(*b) - serial(a, b)).abs() <= bound);
+        }
+        let (a, b) = ([0.1_f32, 0.2, 0.3], [4.0_f32, 5.0, 6.0]);
+        assert_eq!(
+            a.dot(b).to_bits(),
+            (0.1_f32 * 4.0 + 0.2 * 5.0 + 0.3 * 6.0).to_bits()
+        );
+    }
+
+    #[test]
+    fn norm_p_cases() {
+        assert!(([3.0_f64, 4.0].norm_p(2.0) - 5.0).abs() <= f64::EPSILON * 8.0);
+        assert!(([1.0_f64, 4.0].norm_p(0.5) - 9.0).abs() <= f64::EPSILON * 64.0);
+        assert!(([1.0_f32, 1.0, 1.0].norm_p(3.0) - 3f32.cbrt()).abs() <= f32::EPSILON * 8.0);
+        assert_eq!([-1.0_f64, 2.0, -3.0].norm_p(1.0), 6.0);
+        assert_eq!([-1.0_f64, 2.0, -3.0].norm_p(f64::INFINITY), 3.0);
+        assert!([f64::NAN, 1.0].norm_p(2.0).is_nan());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn norm_p_vec_and_box() {
+        use alloc::boxed::Box;
+
+        let a = [[3.0_f64, -4.0], [1.0, 0.5]];
+        assert_eq!(a.to_vec().norm_p(3.0), a.norm_p(3.0));
+        assert_eq!(Box::new(a).norm_p(3.0), a.norm_p(3.0));
+        assert_eq!(a.to_vec().norm_p(f64::INFINITY), 4.0);
+    }
+
+    /// Runs against `std` and, with `--no-default-features --features libm`, against `libm`; the
+    /// correctly rounded functions must agree bit for bit and the others to within a few ulps.
+    #[test]
+    fn float_backend_parity() {
+        assert_eq!(<f64 as Float>::sqrt(2.0), core::f64::consts::SQRT_2);
+        assert_eq!(<f32 as Float>::sqrt(2.0), core::f32::consts::SQRT_2);
+        assert_eq!(
+            <f64 as Float>::mul_add(0.1, 10.0, -1.0),
+            5.551115123125783e-17
+        );
+        assert_eq!(<f64 as Float>::round(-2.5), -3.0);
+        assert_eq!(<f64 as Float>::round_ties_even(-2.5), -2.0);
+        assert_eq!(<f32 as Float>::floor(-0.5), -1.0);
+        assert_eq!(<f32 as Float>::ceil(-0.5), 0.0);
+        assert_eq!(<f64 as Float>::powi(3.0, -2), 1.0 / 9.0);
+        let ulps = |a: f64, b: f64| (a - b).abs() <= 4.0 * f64::EPSILON * b.abs();
+        assert!(ulps(<f64 as Float>::sin(1.0), 0.8414709848078965));
+        assert!(ulps(<f64 as Float>::acos(-0.5), 2.0943951023931957));
+        assert!(ulps(<f64 as Float>::atan2(1.0, -1.0), 2.356194490192345));
+        assert!(ulps(<f64 as Float>::ln(10.0), core::f64::consts::LN_10));
+        assert!(ulps(
+            <f64 as Float>::powf(2.0, 0.5),
+            core::f64::consts::SQRT_2
+        ));
+        let v = [[3.0_f64, 4.0], [0.0, 12.0]];
+        assert_eq!(v.norm(), 13.0);
+        assert_eq!(
+            v.normalized(),
+            [[3.0 / 13.0, 4.0 / 13.0], [0.0, 12.0 / 13.0]]
+        );
+        assert!(ulps(
+            [1.0_f64, 0.0].angle_between([0.0, 1.0]),
+            core::f64::consts::FRAC_PI_2
+        ));
+    }
+
+    #[test]
+    fn scal_add() {
+        assert_eq!([1.0_f32, 2.0, 3.0].scal_add(10.0), [11.0, 12.0, 13.0]);
+        assert_eq!([[1_i32, -2], [3, 0]].scal_add(-3), [[-2, -5], [0, -3]]);
+        assert_eq!(2.5_f64.scal_add(0.5), 3.0);
+        let v = [[0.5_f64, 1.0], [2.0, -4.0]];
+        assert_eq!(v.scal_add(1.5), v.add(<[[f64; 2]; 2]>::splat(1.5)));
+        let mut m = v;
+        m.mut_scal_add(1.5);
+        assert_eq!(m, v.scal_add(1.5));
+        let mut n = [7_u32, 8];
+        n.mut_scal_add(2);
+        assert_eq!(n, [9, 10]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn scal_add_vec_and_box() {
+        use alloc::{boxed::Box, vec};
+
+        assert_eq!(
+            vec![[1.0_f32, 2.0], [3.0, 4.0]].scal_add(1.0),
+            vec![[2.0, 3.0], [4.0, 5.0]]
+        );
+        let mut b = Box::new([1_i64, 2, 3]);
+        b.mut_scal_add(-1);
+        assert_eq!(*b.scal_add(10), [10, 11, 12]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_flat_100k() {
+        let x: alloc::vec::Vec<f64> = par_input(12500, 5).into_iter().flatten().collect();
+        let y: alloc::vec::Vec<f64> = par_input(12500, 6).into_iter().flatten().collect();
+        assert_eq!(x.len(), 100_000);
+        let abs_dot = x.iter().zip(&y).map(|(a, b)| (a * b).abs()).sum::<f64>();
+        assert!((x.par_dot(&y) - x.clone().dot(y.clone())).abs() <= 1e-12 * abs_dot);
+        let mut p = x.clone();
+        p.mut_par_add(&y);
+        assert_eq!(p, x.clone().add(y.clone()));
+        // Below `PAR_MIN_LEAVES` everything runs as one task, in the serial order.
+        let (a, b) = (&x[..PAR_MIN_LEAVES], &y[..PAR_MIN_LEAVES]);
+        let serial = a.iter().zip(b).fold(0.0, |s, (v, w)| s + v * w);
+        assert_eq!(a.par_dot(b), serial);
+    }
+
+    /// The formulas of the former per-type `impl_vector_space!` macro, which the generic
+    /// `Scalar` impls must reproduce exactly. Rows are shorter than a `simd` chunk, so the
+    /// summation order is the same with and without that feature.
+    #[test]
+    fn scalar_generic_matches_former_macro() {
+        fn check<S: Float + core::fmt::Debug>(x: [[S; 3]; 5], y: [[S; 3]; 5]) {
+            let row_dot = |v: &[S; 3], w: &[S; 3]| {
+                v.iter()
+                    .zip(w)
+                    .map(|(a, b)| *a * *b)
+                    .fold(S::ZERO, |s, p| s + p)
+            };
+            let dot = x
+                .iter()
+                .zip(&y)
+                .map(|(v, w)| row_dot(v, w))
+                .fold(S::ZERO, |s, p| s + p);
+            let norm2 = x.iter().map(|v| row_dot(v, v)).fold(S::ZERO, |s, p| s + p);
+            let n = S::recip(S::sqrt(norm2));
+            let zip = |f: fn(S, S) -> S| -> [[S; 3]; 5] {
+                core::array::from_fn(|i| core::array::from_fn(|j| f(x[i][j], y[i][j])))
+            };
+            assert_eq!(x.dot(y), dot);
+            assert_eq!(x.norm2(), norm2);
+            assert_eq!(x.normalized(), x.map(|r| r.map(|a| a * n)));
+            assert_eq!(x.add(y), zip(|a, b| a + b));
+            assert_eq!(x.sub(y), zip(|a, b| a - b));
+            assert_eq!(x.mul(y), zip(|a, b| a * b));
+            assert_eq!(x.div(y), zip(|a, b| a / b));
+        }
+        let x = <[[f64; 3]; 5]>::from_fn(|i| 0.1 * i as f64 - 0.7);
+        let y = <[[f64; 3]; 5]>::from_fn(|i| 1.3 / (i as f64 + 1.0));
+        check(x, y);
+        check(
+            x.map(|r| r.map(|a| a as f32)),
+            y.map(|r| r.map(|a| a as f32)),
+        );
+    }
+
+    #[test]
+    fn is_zero() {
+        assert!([0.0_f32; 3].is_zero(f32::EPSILON));
+        assert!(![0.0, 0.0, f32::EPSILON * 2.0].is_zero(f32::EPSILON));
+        assert!(![0.0_f32, f32::EPSILON].is_zero(f32::EPSILON));
+        assert!([[1e-9_f64, -1e-9], [0.0, -0.0]].is_zero(1e-8));
+        assert!(![[0.0_f64], [f64::NAN]].is_zero(1.0));
+        assert!((-0.0_f64).is_zero(f64::MIN_POSITIVE));
+        assert!(([] as [f64; 0]).is_zero(0.0));
+    }
+
+    #[test]
+    fn is_exactly_zero() {
+        assert!([[0_i32; 2]; 3].is_exactly_zero());
+        assert!(![0_u64, 0, 1].is_exactly_zero());
+        assert!([0.0_f64, -0.0].is_exactly_zero());
+        assert!(![0.0_f64, f64::MIN_POSITIVE / 2.0].is_exactly_zero());
+        assert!(![f64::NAN].is_exactly_zero());
+        assert!(<[[f32; 4]; 2]>::zero().is_exactly_zero());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn is_zero_vec_and_box() {
+        use alloc::{boxed::Box, vec};
+
+        assert!(vec![[0.0_f64, 1e-12]; 4].is_zero(1e-9));
+        assert!(!vec![[0_i32, 1]; 2].is_exactly_zero());
+        assert!(Box::new([[0_i32; 3]; 2]).is_exactly_zero());
+    }
+}
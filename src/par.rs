@@ -0,0 +1,143 @@
+//! Parallel variants on `[V]`, behind the `rayon` feature; arrays, `Vec` and `Box<[V; N]>` reach
+//! them through deref. Only the outermost level is split across tasks, each `V` is processed
+//! serially, and a task gets at least `PAR_MIN_LEAVES` leaves so that small inputs stay on a
+//! single task. The reductions add up per-task partial sums in an order that depends on the
+//! scheduling, so they differ from their serial counterparts by rounding and may differ between
+//! runs. The element-wise operations give the same results as the serial ones. Slices cannot be
+//! taken by value, so those only exist in place.
+
+use rayon::prelude::*;
+
+use crate::{
+    ArrayVectorSpaceFloat, ArrayVectorSpaceFloatMut, ArrayVectorSpaceInit, ArrayVectorSpaceMut,
+    Float, Scalar,
+};
+
+/// Minimum number of leaves handled by a single parallel task.
+pub const PAR_MIN_LEAVES: usize = 4096;
+
+fn min_len<S, V: ArrayVectorSpaceInit<S>>() -> usize {
+    (PAR_MIN_LEAVES / V::DIM.max(1)).max(1)
+}
+
+fn check_len(a: usize, b: usize) {
+    assert_eq!(a, b, "parallel operands have mismatched lengths");
+}
+
+pub trait ArrayVectorSpacePar<T> {
+    fn par_dot(&self, rhs: &Self) -> T;
+    fn par_sum(&self) -> T;
+    fn par_norm2(&self) -> T;
+    fn mut_par_add(&mut self, rhs: &Self);
+    fn mut_par_sub(&mut self, rhs: &Self);
+    fn mut_par_mul(&mut self, rhs: &Self);
+    fn mut_par_div(&mut self, rhs: &Self);
+    fn mut_par_scal_mul(&mut self, rhs: T);
+    fn mut_par_scal_add(&mut self, rhs: T);
+}
+
+impl<S, V> ArrayVectorSpacePar<S> for [V]
+where
+    S: Scalar + Send + Sync,
+    V: ArrayVectorSpaceInit<S> + ArrayVectorSpaceMut<S> + Copy + Send + Sync,
+{
+    fn par_dot(&self, rhs: &Self) -> S {
+        check_len(self.len(), rhs.len());
+        self.par_iter()
+            .zip(rhs)
+            .with_min_len(min_len::<S, V>())
+            .map(|(v, w)| v.dot(*w))
+            .reduce(|| S::ZERO, |a, b| a + b)
+    }
+    fn par_sum(&self) -> S {
+        self.par_iter()
+            .with_min_len(min_len::<S, V>())
+            .map(|v| v.sum())
+            .reduce(|| S::ZERO, |a, b| a + b)
+    }
+    fn par_norm2(&self) -> S {
+        self.par_dot(self)
+    }
+    fn mut_par_add(&mut self, rhs: &Self) {
+        check_len(self.len(), rhs.len());
+        self.par_iter_mut()
+            .zip(rhs)
+            .with_min_len(min_len::<S, V>())
+            .for_each(|(v, w)| v.mut_add(w));
+    }
+    fn mut_par_sub(&mut self, rhs: &Self) {
+        check_len(self.len(), rhs.len());
+        self.par_iter_mut()
+            .zip(rhs)
+            .with_min_len(min_len::<S, V>())
+            .for_each(|(v, w)| v.mut_sub(w));
+    }
+    fn mut_par_mul(&mut self, rhs: &Self) {
+        check_len(self.len(), rhs.len());
+        self.par_iter_mut()
+            .zip(rhs)
+            .with_min_len(min_len::<S, V>())
+            .for_each(|(v, w)| v.mut_mul(w));
+    }
+    fn mut_par_div(&mut self, rhs: &Self) {
+        check_len(self.len(), rhs.len());
+        self.par_iter_mut()
+            .zip(rhs)
+            .with_min_len(min_len::<S, V>())
+            .for_each(|(v, w)| v.mut_div(w));
+    }
+    fn mut_par_scal_mul(&mut self, rhs: S) {
+        self.par_iter_mut()
+            .with_min_len(min_len::<S, V>())
+            .for_each(|v| v.mut_scal_mul(rhs));
+    }
+    fn mut_par_scal_add(&mut self, rhs: S) {
+        self.par_iter_mut()
+            .with_min_len(min_len::<S, V>())
+            .for_each(|v| v.mut_scal_add(rhs));
+    }
+}
+
+pub trait ArrayVectorSpaceFloatPar<T>: ArrayVectorSpacePar<T> {
+    fn par_norm(&self) -> T;
+    fn par_distance2(&self, rhs: &Self) -> T;
+    fn mut_par_scale_add(&mut self, rhs: &Self, factor: T);
+    fn mut_par_lerp(&mut self, rhs: &Self, t: T);
+}
+
+impl<S, V> ArrayVectorSpaceFloatPar<S> for [V]
+where
+    S: Float + Send + Sync,
+    V: ArrayVectorSpaceInit<S>
+        + ArrayVectorSpaceFloat<S>
+        + ArrayVectorSpaceFloatMut<S>
+        + Copy
+        + Send
+        + Sync,
+{
+    fn par_norm(&self) -> S {
+        S::sqrt(self.par_norm2())
+    }
+    fn par_distance2(&self, rhs: &Self) -> S {
+        check_len(self.len(), rhs.len());
+        self.par_iter()
+            .zip(rhs)
+            .with_min_len(min_len::<S, V>())
+            .map(|(v, w)| v.distance2(*w))
+            .reduce(|| S::ZERO, |a, b| a + b)
+    }
+    fn mut_par_scale_add(&mut self, rhs: &Self, factor: S) {
+        check_len(self.len(), rhs.len());
+        self.par_iter_mut()
+            .zip(rhs)
+            .with_min_len(min_len::<S, V>())
+            .for_each(|(v, w)| v.mut_scale_add(w, factor));
+    }
+    fn mut_par_lerp(&mut self, rhs: &Self, t: S) {
+        check_len(self.len(), rhs.len());
+        self.par_iter_mut()
+            .zip(rhs)
+            .with_min_len(min_len::<S, V>())
+            .for_each(|(v, w)| v.mut_lerp(w, t));
+    }
+}
@@ -0,0 +1,329 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use libm::Libm;
+
+/// Leaf type of the vector spaces. Its operations are associated functions rather than methods so
+/// that they never shadow `Ord::min` and friends on the integer types.
+pub trait Scalar:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+    fn min(a: Self, b: Self) -> Self;
+    fn max(a: Self, b: Self) -> Self;
+    fn clamp(x: Self, min: Self, max: Self) -> Self;
+    fn signum(x: Self) -> Self;
+    /// `(a + b) / 2` without overflowing; signed integers round towards zero.
+    fn midpoint(a: Self, b: Self) -> Self;
+    fn from_usize(n: usize) -> Self;
+    /// Kernels for the innermost arrays, which the `simd` feature vectorizes for `f32` and `f64`.
+    #[doc(hidden)]
+    fn dot_slice(x: &[Self], y: &[Self]) -> Self {
+        x.iter().zip(y).fold(Self::ZERO, |a, (v, w)| a + *v * *w)
+    }
+    #[doc(hidden)]
+    fn add_slice(x: &mut [Self], y: &[Self]) {
+        x.iter_mut().zip(y).for_each(|(v, w)| *v += *w);
+    }
+    #[doc(hidden)]
+    fn mul_slice(x: &mut [Self], y: &[Self]) {
+        x.iter_mut().zip(y).for_each(|(v, w)| *v *= *w);
+    }
+    #[doc(hidden)]
+    fn scal_mul_slice(x: &mut [Self], rhs: Self) {
+        x.iter_mut().for_each(|v| *v *= rhs);
+    }
+}
+
+pub trait Float: Scalar + Neg<Output = Self> {
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+    const NAN: Self;
+    const EPSILON: Self;
+    fn is_nan(x: Self) -> bool;
+    fn is_finite(x: Self) -> bool;
+    fn abs(x: Self) -> Self;
+    fn sqrt(x: Self) -> Self;
+    fn recip(x: Self) -> Self;
+    fn floor(x: Self) -> Self;
+    fn ceil(x: Self) -> Self;
+    fn round(x: Self) -> Self;
+    fn round_ties_even(x: Self) -> Self;
+    fn exp(x: Self) -> Self;
+    fn ln(x: Self) -> Self;
+    fn exp2(x: Self) -> Self;
+    fn log2(x: Self) -> Self;
+    fn sin(x: Self) -> Self;
+    fn cos(x: Self) -> Self;
+    fn tan(x: Self) -> Self;
+    fn asin(x: Self) -> Self;
+    fn acos(x: Self) -> Self;
+    fn atan(x: Self) -> Self;
+    fn mul_add(x: Self, a: Self, b: Self) -> Self;
+    fn powf(x: Self, n: Self) -> Self;
+    fn powi(x: Self, n: i32) -> Self;
+    fn atan2(y: Self, x: Self) -> Self;
+}
+
+/// Integer leaves, whose arithmetic wraps or panics on overflow instead of rounding.
+pub trait Integer: Scalar + Ord {
+    fn checked_add(a: Self, b: Self) -> Option<Self>;
+    fn checked_mul(a: Self, b: Self) -> Option<Self>;
+}
+
+macro_rules! impl_scalar {
+    ($t: ty, $cmp: ident, $zero: literal, $one: literal, $signum: expr $(, $simd: ident)?) => {
+        impl Scalar for $t {
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
+            fn min(a: Self, b: Self) -> Self {
+                $cmp::min(a, b)
+            }
+            fn max(a: Self, b: Self) -> Self {
+                $cmp::max(a, b)
+            }
+            fn clamp(x: Self, min: Self, max: Self) -> Self {
+                $cmp::clamp(x, min, max)
+            }
+            fn signum(x: Self) -> Self {
+                ($signum)(x)
+            }
+            fn midpoint(a: Self, b: Self) -> Self {
+                <$t>::midpoint(a, b)
+            }
+            fn from_usize(n: usize) -> Self {
+                n as $t
+            }
+            $(
+                #[cfg(feature = "simd")]
+                #[inline]
+                fn dot_slice(x: &[Self], y: &[Self]) -> Self {
+                    crate::simd::$simd::dot(x, y)
+                }
+                #[cfg(feature = "simd")]
+                #[inline]
+                fn add_slice(x: &mut [Self], y: &[Self]) {
+                    crate::simd::$simd::add(x, y)
+                }
+                #[cfg(feature = "simd")]
+                #[inline]
+                fn mul_slice(x: &mut [Self], y: &[Self]) {
+                    crate::simd::$simd::mul(x, y)
+                }
+                #[cfg(feature = "simd")]
+                #[inline]
+                fn scal_mul_slice(x: &mut [Self], rhs: Self) {
+                    crate::simd::$simd::scal_mul(x, rhs)
+                }
+            )?
+        }
+    };
+}
+
+impl_scalar! {f32, f32, 0.0, 1.0, f32::signum, lanes_f32}
+impl_scalar! {f64, f64, 0.0, 1.0, f64::signum, lanes_f64}
+impl_scalar! {i32, Ord, 0, 1, i32::signum}
+impl_scalar! {i64, Ord, 0, 1, i64::signum}
+impl_scalar! {u32, Ord, 0, 1, |x| (x != 0) as u32}
+impl_scalar! {u64, Ord, 0, 1, |x| (x != 0) as u64}
+
+macro_rules! impl_integer {
+    ($t: ident) => {
+        impl Integer for $t {
+            fn checked_add(a: Self, b: Self) -> Option<Self> {
+                a.checked_add(b)
+            }
+            fn checked_mul(a: Self, b: Self) -> Option<Self> {
+                a.checked_mul(b)
+            }
+        }
+    };
+}
+
+impl_integer! {i32}
+impl_integer! {i64}
+impl_integer! {u32}
+impl_integer! {u64}
+
+#[cfg(feature = "std")]
+macro_rules! impl_float {
+    ($t: ident) => {
+        impl Float for $t {
+            const INFINITY: Self = $t::INFINITY;
+            const NEG_INFINITY: Self = $t::NEG_INFINITY;
+            const NAN: Self = $t::NAN;
+            const EPSILON: Self = $t::EPSILON;
+            fn is_nan(x: Self) -> bool {
+                x.is_nan()
+            }
+            fn is_finite(x: Self) -> bool {
+                x.is_finite()
+            }
+            fn abs(x: Self) -> Self {
+                x.abs()
+            }
+            fn sqrt(x: Self) -> Self {
+                x.sqrt()
+            }
+            fn recip(x: Self) -> Self {
+                x.recip()
+            }
+            fn floor(x: Self) -> Self {
+                x.floor()
+            }
+            fn ceil(x: Self) -> Self {
+                x.ceil()
+            }
+            fn round(x: Self) -> Self {
+                x.round()
+            }
+            fn round_ties_even(x: Self) -> Self {
+                x.round_ties_even()
+            }
+            fn exp(x: Self) -> Self {
+                x.exp()
+            }
+            fn ln(x: Self) -> Self {
+                x.ln()
+            }
+            fn exp2(x: Self) -> Self {
+                x.exp2()
+            }
+            fn log2(x: Self) -> Self {
+                x.log2()
+            }
+            fn sin(x: Self) -> Self {
+                x.sin()
+            }
+            fn cos(x: Self) -> Self {
+                x.cos()
+            }
+            fn tan(x: Self) -> Self {
+                x.tan()
+            }
+            fn asin(x: Self) -> Self {
+                x.asin()
+            }
+            fn acos(x: Self) -> Self {
+                x.acos()
+            }
+            fn atan(x: Self) -> Self {
+                x.atan()
+            }
+            fn mul_add(x: Self, a: Self, b: Self) -> Self {
+                x.mul_add(a, b)
+            }
+            fn powf(x: Self, n: Self) -> Self {
+                x.powf(n)
+            }
+            fn powi(x: Self, n: i32) -> Self {
+                x.powi(n)
+            }
+            fn atan2(y: Self, x: Self) -> Self {
+                y.atan2(x)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+impl_float! {f32}
+#[cfg(feature = "std")]
+impl_float! {f64}
+
+/// Without `std`, the float functions come from `libm`, with `powi` computed through `pow`.
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+macro_rules! impl_float_libm {
+    ($t: ident) => {
+        impl Float for $t {
+            const INFINITY: Self = $t::INFINITY;
+            const NEG_INFINITY: Self = $t::NEG_INFINITY;
+            const NAN: Self = $t::NAN;
+            const EPSILON: Self = $t::EPSILON;
+            fn is_nan(x: Self) -> bool {
+                x.is_nan()
+            }
+            fn is_finite(x: Self) -> bool {
+                x.is_finite()
+            }
+            fn abs(x: Self) -> Self {
+                x.abs()
+            }
+            fn sqrt(x: Self) -> Self {
+                Libm::<$t>::sqrt(x)
+            }
+            fn recip(x: Self) -> Self {
+                x.recip()
+            }
+            fn floor(x: Self) -> Self {
+                Libm::<$t>::floor(x)
+            }
+            fn ceil(x: Self) -> Self {
+                Libm::<$t>::ceil(x)
+            }
+            fn round(x: Self) -> Self {
+                Libm::<$t>::round(x)
+            }
+            fn round_ties_even(x: Self) -> Self {
+                Libm::<$t>::roundeven(x)
+            }
+            fn exp(x: Self) -> Self {
+                Libm::<$t>::exp(x)
+            }
+            fn ln(x: Self) -> Self {
+                Libm::<$t>::log(x)
+            }
+            fn exp2(x: Self) -> Self {
+                Libm::<$t>::exp2(x)
+            }
+            fn log2(x: Self) -> Self {
+                Libm::<$t>::log2(x)
+            }
+            fn sin(x: Self) -> Self {
+                Libm::<$t>::sin(x)
+            }
+            fn cos(x: Self) -> Self {
+                Libm::<$t>::cos(x)
+            }
+            fn tan(x: Self) -> Self {
+                Libm::<$t>::tan(x)
+            }
+            fn asin(x: Self) -> Self {
+                Libm::<$t>::asin(x)
+            }
+            fn acos(x: Self) -> Self {
+                Libm::<$t>::acos(x)
+            }
+            fn atan(x: Self) -> Self {
+                Libm::<$t>::atan(x)
+            }
+            fn mul_add(x: Self, a: Self, b: Self) -> Self {
+                Libm::<$t>::fma(x, a, b)
+            }
+            fn powf(x: Self, n: Self) -> Self {
+                Libm::<$t>::pow(x, n)
+            }
+            fn powi(x: Self, n: i32) -> Self {
+                Libm::<$t>::pow(x, n as $t)
+            }
+            fn atan2(y: Self, x: Self) -> Self {
+                Libm::<$t>::atan2(y, x)
+            }
+        }
+    };
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl_float_libm! {f32}
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl_float_libm! {f64}
@@ -0,0 +1,58 @@
+//! Lane-wise kernels for the innermost `[f32; N]` and `[f64; N]` arrays, behind the `simd`
+//! feature. Leaves are processed eight `f32` or four `f64` at a time, then the remainder one by
+//! one. The element-wise kernels give the same results as the scalar loops. `dot` keeps one
+//! partial sum per lane and adds them up before the remainder, so it only matches the serial fold
+//! up to rounding once there is a full chunk.
+
+macro_rules! kernels {
+    ($m: ident, $t: ident, $v: ident, $lanes: literal) => {
+        pub(crate) mod $m {
+            use wide::$v;
+
+            #[inline]
+            pub(crate) fn dot(x: &[$t], y: &[$t]) -> $t {
+                let (xc, xt) = x.as_chunks::<$lanes>();
+                let (yc, yt) = y.as_chunks::<$lanes>();
+                let acc = xc
+                    .iter()
+                    .zip(yc)
+                    .fold($v::ZERO, |acc, (a, b)| acc + $v::from(*a) * $v::from(*b));
+                xt.iter()
+                    .zip(yt)
+                    .fold(acc.reduce_add(), |s, (a, b)| s + a * b)
+            }
+
+            #[inline]
+            pub(crate) fn add(x: &mut [$t], y: &[$t]) {
+                let (xc, xt) = x.as_chunks_mut::<$lanes>();
+                let (yc, yt) = y.as_chunks::<$lanes>();
+                xc.iter_mut()
+                    .zip(yc)
+                    .for_each(|(a, b)| *a = ($v::from(*a) + $v::from(*b)).to_array());
+                xt.iter_mut().zip(yt).for_each(|(a, b)| *a += b);
+            }
+
+            #[inline]
+            pub(crate) fn mul(x: &mut [$t], y: &[$t]) {
+                let (xc, xt) = x.as_chunks_mut::<$lanes>();
+                let (yc, yt) = y.as_chunks::<$lanes>();
+                xc.iter_mut()
+                    .zip(yc)
+                    .for_each(|(a, b)| *a = ($v::from(*a) * $v::from(*b)).to_array());
+                xt.iter_mut().zip(yt).for_each(|(a, b)| *a *= b);
+            }
+
+            #[inline]
+            pub(crate) fn scal_mul(x: &mut [$t], r: $t) {
+                let (xc, xt) = x.as_chunks_mut::<$lanes>();
+                let rv = $v::splat(r);
+                xc.iter_mut()
+                    .for_each(|a| *a = ($v::from(*a) * rv).to_array());
+                xt.iter_mut().for_each(|a| *a *= r);
+            }
+        }
+    };
+}
+
+kernels! {lanes_f32, f32, f32x8, 8}
+kernels! {lanes_f64, f64, f64x4, 4}
@@ -0,0 +1,717 @@
+//! Runtime-length `Vec<V>`, and `[V]` for the in-place operations. Operands must have matching
+//! lengths, otherwise the operation panics. A `Vec` has no compile-time length to build from, so
+//! it does not implement `ArrayVectorSpaceInit`; start from `vec![V::zero(); len]` instead.
+
+use alloc::vec::Vec;
+use core::ops::Neg;
+
+use crate::{
+    ArrayVectorSpace, ArrayVectorSpaceFloat, ArrayVectorSpaceFloatMut, ArrayVectorSpaceInit,
+    ArrayVectorSpaceInteger, ArrayVectorSpaceMut, ArrayVectorSpacePredicates, Float, Integer,
+    Scalar, pairwise_dot,
+};
+
+fn check_len(a: usize, b: usize) {
+    assert_eq!(a, b, "runtime-length vectors have mismatched lengths");
+}
+
+fn dot<S: Scalar, V: ArrayVectorSpace<S> + Copy>(x: &[V], y: &[V]) -> S {
+    V::dot_slice(x, y)
+}
+
+pub(crate) fn norm2<S: Scalar, V: ArrayVectorSpace<S> + Copy>(x: &[V]) -> S {
+    dot(x, x)
+}
+
+fn norm_inf<S: Float, V: ArrayVectorSpace<S> + Copy>(x: &[V]) -> S {
+    x.iter().fold(S::ZERO, |m, v| {
+        v.fold_scalar(m, |m, s| {
+            let n = S::abs(s);
+            if n > m || S::is_nan(n) { n } else { m }
+        })
+    })
+}
+
+pub(crate) fn mut_normalized<S: Float, V: ArrayVectorSpaceMut<S> + ArrayVectorSpace<S> + Copy>(
+    x: &mut [V],
+) {
+    let n = S::sqrt(norm2(x));
+    x.mut_scal_mul(S::recip(n))
+}
+
+pub(crate) fn mut_try_normalized<
+    S: Float,
+    V: ArrayVectorSpaceMut<S> + ArrayVectorSpace<S> + Copy,
+>(
+    x: &mut [V],
+) -> bool {
+    let m = norm_inf(x);
+    if m == S::ZERO || !S::is_finite(m) {
+        return false;
+    }
+    x.mut_scal_div(m);
+    let n = S::sqrt(norm2(x));
+    x.mut_scal_mul(S::recip(n));
+    true
+}
+
+impl<S: Scalar, V: ArrayVectorSpaceInit<S> + Copy> ArrayVectorSpace<S> for Vec<V> {
+    fn dot(self, rhs: Self) -> S {
+        check_len(self.len(), rhs.len());
+        dot(&self, &rhs)
+    }
+    fn sum(self) -> S {
+        self.into_iter()
+            .map(|v| v.sum())
+            .fold(S::ZERO, |a, b| a + b)
+    }
+    fn product(self) -> S {
+        self.into_iter()
+            .map(|v| v.product())
+            .fold(S::ONE, |a, b| a * b)
+    }
+    fn mean(self) -> S {
+        let n = self.len() * V::DIM;
+        self.sum() / S::from_usize(n)
+    }
+    fn norm2(self) -> S
+    where
+        Self: Sized + Clone,
+    {
+        norm2(&self)
+    }
+    fn distance2(self, rhs: Self) -> S
+    where
+        Self: Sized + Clone,
+    {
+        check_len(self.len(), rhs.len());
+        self.iter()
+            .zip(&rhs)
+            .map(|(v, w)| v.sub(*w).norm2())
+            .fold(S::ZERO, |a, b| a + b)
+    }
+    fn add(mut self, rhs: Self) -> Self {
+        check_len(self.len(), rhs.len());
+        V::add_slice(&mut self, &rhs);
+        self
+    }
+    fn sub(mut self, rhs: Self) -> Self {
+        check_len(self.len(), rhs.len());
+        self.iter_mut().zip(rhs).for_each(|(v, w)| *v = v.sub(w));
+        self
+    }
+    fn mul(mut self, rhs: Self) -> Self {
+        check_len(self.len(), rhs.len());
+        V::mul_slice(&mut self, &rhs);
+        self
+    }
+    fn div(mut self, rhs: Self) -> Self {
+        check_len(self.len(), rhs.len());
+        self.iter_mut().zip(rhs).for_each(|(v, w)| *v = v.div(w));
+        self
+    }
+    fn neg(mut self) -> Self
+    where
+        S: Neg<Output = S>,
+    {
+        self.iter_mut().for_each(|v| *v = v.neg());
+        self
+    }
+    fn scal_mul(mut self, rhs: S) -> Self {
+        V::scal_mul_slice(&mut self, rhs);
+        self
+    }
+    fn scal_div(mut self, rhs: S) -> Self {
+        self.iter_mut().for_each(|v| *v = v.scal_div(rhs));
+        self
+    }
+    fn scal_add(mut self, rhs: S) -> Self {
+        self.iter_mut().for_each(|v| *v = v.scal_add(rhs));
+        self
+    }
+    fn scal_sub(mut self, rhs: S) -> Self {
+        self.iter_mut().for_each(|v| *v = v.scal_sub(rhs));
+        self
+    }
+    fn clamp(mut self, min: S, max: S) -> Self {
+        self.iter_mut().for_each(|v| *v = v.clamp(min, max));
+        self
+    }
+    fn clamp_vec(mut self, min: Self, max: Self) -> Self {
+        check_len(self.len(), min.len());
+        check_len(self.len(), max.len());
+        self.iter_mut()
+            .zip(min.into_iter().zip(max))
+            .for_each(|(v, (a, b))| *v = v.clamp_vec(a, b));
+        self
+    }
+    fn signum(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.signum());
+        self
+    }
+    fn map_scalar<F: FnMut(S) -> S>(mut self, mut f: F) -> Self {
+        self.iter_mut().for_each(|v| *v = v.map_scalar(&mut f));
+        self
+    }
+    fn zip_with_scalar<F: FnMut(S, S) -> S>(mut self, rhs: Self, mut f: F) -> Self {
+        check_len(self.len(), rhs.len());
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| *v = v.zip_with_scalar(w, &mut f));
+        self
+    }
+    fn fold_scalar<A, F: FnMut(A, S) -> A>(self, init: A, mut f: F) -> A {
+        self.into_iter()
+            .fold(init, |acc, v| v.fold_scalar(acc, &mut f))
+    }
+    fn min_comp(mut self, rhs: Self) -> Self {
+        check_len(self.len(), rhs.len());
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| *v = v.min_comp(w));
+        self
+    }
+    fn max_comp(mut self, rhs: Self) -> Self {
+        check_len(self.len(), rhs.len());
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| *v = v.max_comp(w));
+        self
+    }
+}
+
+impl<S: Float, V: ArrayVectorSpaceFloat<S> + ArrayVectorSpaceInit<S> + Copy>
+    ArrayVectorSpaceFloat<S> for Vec<V>
+{
+    fn norm(self) -> S {
+        S::sqrt(norm2(&self))
+    }
+    fn dot_compensated(self, rhs: Self) -> S {
+        let (sum, c) = self.dot_compensated_acc(rhs, (S::ZERO, S::ZERO));
+        sum + c
+    }
+    fn dot_compensated_acc(self, rhs: Self, acc: (S, S)) -> (S, S) {
+        check_len(self.len(), rhs.len());
+        self.into_iter()
+            .zip(rhs)
+            .fold(acc, |acc, (v, w)| v.dot_compensated_acc(w, acc))
+    }
+    fn dot_pairwise_with(self, rhs: Self, threshold: usize) -> S {
+        check_len(self.len(), rhs.len());
+        pairwise_dot(&self, &rhs, threshold)
+    }
+    fn norm_l1(self) -> S {
+        self.into_iter()
+            .map(|v| v.norm_l1())
+            .fold(S::ZERO, |a, b| a + b)
+    }
+    fn norm_inf(self) -> S {
+        norm_inf(&self)
+    }
+    fn min_element(self) -> S {
+        self.into_iter()
+            .map(|v| v.min_element())
+            .fold(S::INFINITY, S::min)
+    }
+    fn max_element(self) -> S {
+        self.into_iter()
+            .map(|v| v.max_element())
+            .fold(S::NEG_INFINITY, S::max)
+    }
+    fn norm_p(self, p: S) -> S {
+        let m = norm_inf(&self);
+        if m == S::ZERO || !S::is_finite(m) || p == S::INFINITY {
+            return m;
+        }
+        m * S::powf(self.scal_div(m).norm_p_sum(p), S::recip(p))
+    }
+    fn norm_p_sum(self, p: S) -> S {
+        self.into_iter()
+            .map(|v| v.norm_p_sum(p))
+            .fold(S::ZERO, |a, b| a + b)
+    }
+    fn abs(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.abs());
+        self
+    }
+    fn recip(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.recip());
+        self
+    }
+    fn floor(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.floor());
+        self
+    }
+    fn ceil(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.ceil());
+        self
+    }
+    fn round(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.round());
+        self
+    }
+    fn round_ties_even(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.round_ties_even());
+        self
+    }
+    fn sqrt(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.sqrt());
+        self
+    }
+    fn exp(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.exp());
+        self
+    }
+    fn ln(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.ln());
+        self
+    }
+    fn exp2(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.exp2());
+        self
+    }
+    fn log2(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.log2());
+        self
+    }
+    fn sin(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.sin());
+        self
+    }
+    fn cos(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.cos());
+        self
+    }
+    fn tan(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.tan());
+        self
+    }
+    fn asin(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.asin());
+        self
+    }
+    fn acos(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.acos());
+        self
+    }
+    fn atan(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.atan());
+        self
+    }
+    fn atan2(mut self, rhs: Self) -> Self {
+        check_len(self.len(), rhs.len());
+        self.iter_mut().zip(rhs).for_each(|(v, w)| *v = v.atan2(w));
+        self
+    }
+    fn powi(mut self, n: i32) -> Self {
+        self.iter_mut().for_each(|v| *v = v.powi(n));
+        self
+    }
+    fn powf(mut self, rhs: Self) -> Self {
+        check_len(self.len(), rhs.len());
+        self.iter_mut().zip(rhs).for_each(|(v, w)| *v = v.powf(w));
+        self
+    }
+    fn powf_scalar(mut self, exp: S) -> Self {
+        self.iter_mut().for_each(|v| *v = v.powf_scalar(exp));
+        self
+    }
+    fn lerp(mut self, rhs: Self, t: S) -> Self {
+        check_len(self.len(), rhs.len());
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| *v = v.lerp(w, t));
+        self
+    }
+    fn scale_add(mut self, rhs: Self, factor: S) -> Self {
+        check_len(self.len(), rhs.len());
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| *v = v.scale_add(w, factor));
+        self
+    }
+    fn fma(mut self, a: Self, b: Self) -> Self {
+        check_len(self.len(), a.len());
+        check_len(self.len(), b.len());
+        self.iter_mut()
+            .zip(a.into_iter().zip(b))
+            .for_each(|(v, (a, b))| *v = v.fma(a, b));
+        self
+    }
+    fn cosine_similarity(self, rhs: Self) -> S {
+        check_len(self.len(), rhs.len());
+        let n = S::sqrt(norm2(&self)) * S::sqrt(norm2(&rhs));
+        S::clamp(self.dot(rhs) / n, -S::ONE, S::ONE)
+    }
+    fn angle_between(self, rhs: Self) -> S {
+        check_len(self.len(), rhs.len());
+        let (n, m) = (S::sqrt(norm2(&self)), S::sqrt(norm2(&rhs)));
+        if n == S::ZERO || m == S::ZERO {
+            return S::NAN;
+        }
+        let (u, v) = (self.scal_mul(m), rhs.scal_mul(n));
+        let a = S::atan2(u.clone().sub(v.clone()).norm(), u.add(v).norm());
+        a + a
+    }
+    fn refract(self, normal: Self, eta: S) -> Option<Self> {
+        check_len(self.len(), normal.len());
+        let d = dot(&normal, &self);
+        let k = S::ONE - eta * eta * (S::ONE - d * d);
+        (k >= S::ZERO).then(|| {
+            self.scal_mul(eta)
+                .sub(normal.scal_mul(eta * d + S::sqrt(k)))
+        })
+    }
+    fn normalized(self) -> Self {
+        let n = S::sqrt(norm2(&self));
+        self.scal_mul(S::recip(n))
+    }
+    fn try_normalized(self) -> Option<Self> {
+        let m = norm_inf(&self);
+        if m == S::ZERO || !S::is_finite(m) {
+            return None;
+        }
+        let v = self.scal_div(m);
+        let n = S::sqrt(norm2(&v));
+        Some(v.scal_mul(S::recip(n)))
+    }
+    fn normalize_or_zero(self) -> Self {
+        let m = norm_inf(&self);
+        if m == S::ZERO || !S::is_finite(m) {
+            return self.map_scalar(|_| S::ZERO);
+        }
+        let v = self.scal_div(m);
+        let n = S::sqrt(norm2(&v));
+        v.scal_mul(S::recip(n))
+    }
+}
+
+impl<S: Scalar, V: ArrayVectorSpaceMut<S> + Copy> ArrayVectorSpaceMut<S> for [V] {
+    fn mut_add(&mut self, rhs: &Self) {
+        check_len(self.len(), rhs.len());
+        V::mut_add_slice(self, rhs)
+    }
+    fn mut_sub(&mut self, rhs: &Self) {
+        check_len(self.len(), rhs.len());
+        self.iter_mut().zip(rhs).for_each(|(v, w)| v.mut_sub(w));
+    }
+    fn mut_mul(&mut self, rhs: &Self) {
+        check_len(self.len(), rhs.len());
+        V::mut_mul_slice(self, rhs)
+    }
+    fn mut_div(&mut self, rhs: &Self) {
+        check_len(self.len(), rhs.len());
+        self.iter_mut().zip(rhs).for_each(|(v, w)| v.mut_div(w));
+    }
+    fn mut_neg(&mut self)
+    where
+        S: Neg<Output = S>,
+    {
+        self.iter_mut().for_each(|v| v.mut_neg());
+    }
+    fn mut_scal_mul(&mut self, rhs: S) {
+        V::mut_scal_mul_slice(self, rhs)
+    }
+    fn mut_scal_div(&mut self, rhs: S) {
+        self.iter_mut().for_each(|v| v.mut_scal_div(rhs));
+    }
+    fn mut_scal_add(&mut self, rhs: S) {
+        self.iter_mut().for_each(|v| v.mut_scal_add(rhs));
+    }
+    fn mut_scal_sub(&mut self, rhs: S) {
+        self.iter_mut().for_each(|v| v.mut_scal_sub(rhs));
+    }
+    fn mut_clamp(&mut self, min: S, max: S) {
+        self.iter_mut().for_each(|v| v.mut_clamp(min, max));
+    }
+    fn mut_clamp_vec(&mut self, min: &Self, max: &Self) {
+        check_len(self.len(), min.len());
+        check_len(self.len(), max.len());
+        self.iter_mut()
+            .zip(min.iter().zip(max.iter()))
+            .for_each(|(v, (a, b))| v.mut_clamp_vec(a, b));
+    }
+    fn mut_signum(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_signum());
+    }
+    fn mut_min_comp(&mut self, rhs: &Self) {
+        check_len(self.len(), rhs.len());
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| v.mut_min_comp(w));
+    }
+    fn mut_max_comp(&mut self, rhs: &Self) {
+        check_len(self.len(), rhs.len());
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| v.mut_max_comp(w));
+    }
+}
+
+impl<S: Scalar, V: ArrayVectorSpaceMut<S> + Copy> ArrayVectorSpaceMut<S> for Vec<V> {
+    fn mut_add(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_add(rhs)
+    }
+    fn mut_sub(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_sub(rhs)
+    }
+    fn mut_mul(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_mul(rhs)
+    }
+    fn mut_div(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_div(rhs)
+    }
+    fn mut_neg(&mut self)
+    where
+        S: Neg<Output = S>,
+    {
+        self.as_mut_slice().mut_neg()
+    }
+    fn mut_scal_mul(&mut self, rhs: S) {
+        self.as_mut_slice().mut_scal_mul(rhs)
+    }
+    fn mut_scal_div(&mut self, rhs: S) {
+        self.as_mut_slice().mut_scal_div(rhs)
+    }
+    fn mut_scal_add(&mut self, rhs: S) {
+        self.as_mut_slice().mut_scal_add(rhs)
+    }
+    fn mut_scal_sub(&mut self, rhs: S) {
+        self.as_mut_slice().mut_scal_sub(rhs)
+    }
+    fn mut_clamp(&mut self, min: S, max: S) {
+        self.as_mut_slice().mut_clamp(min, max)
+    }
+    fn mut_clamp_vec(&mut self, min: &Self, max: &Self) {
+        self.as_mut_slice().mut_clamp_vec(min, max)
+    }
+    fn mut_signum(&mut self) {
+        self.as_mut_slice().mut_signum()
+    }
+    fn mut_min_comp(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_min_comp(rhs)
+    }
+    fn mut_max_comp(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_max_comp(rhs)
+    }
+}
+
+impl<S: Float, V: ArrayVectorSpaceFloatMut<S> + ArrayVectorSpace<S> + Copy>
+    ArrayVectorSpaceFloatMut<S> for [V]
+{
+    fn mut_abs(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_abs());
+    }
+    fn mut_recip(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_recip());
+    }
+    fn mut_floor(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_floor());
+    }
+    fn mut_ceil(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_ceil());
+    }
+    fn mut_round(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_round());
+    }
+    fn mut_round_ties_even(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_round_ties_even());
+    }
+    fn mut_sqrt(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_sqrt());
+    }
+    fn mut_exp(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_exp());
+    }
+    fn mut_ln(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_ln());
+    }
+    fn mut_exp2(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_exp2());
+    }
+    fn mut_log2(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_log2());
+    }
+    fn mut_sin(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_sin());
+    }
+    fn mut_cos(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_cos());
+    }
+    fn mut_tan(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_tan());
+    }
+    fn mut_asin(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_asin());
+    }
+    fn mut_acos(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_acos());
+    }
+    fn mut_atan(&mut self) {
+        self.iter_mut().for_each(|v| v.mut_atan());
+    }
+    fn mut_atan2(&mut self, rhs: &Self) {
+        check_len(self.len(), rhs.len());
+        self.iter_mut().zip(rhs).for_each(|(v, w)| v.mut_atan2(w));
+    }
+    fn mut_powi(&mut self, n: i32) {
+        self.iter_mut().for_each(|v| v.mut_powi(n));
+    }
+    fn mut_powf(&mut self, rhs: &Self) {
+        check_len(self.len(), rhs.len());
+        self.iter_mut().zip(rhs).for_each(|(v, w)| v.mut_powf(w));
+    }
+    fn mut_powf_scalar(&mut self, exp: S) {
+        self.iter_mut().for_each(|v| v.mut_powf_scalar(exp));
+    }
+    fn mut_lerp(&mut self, rhs: &Self, t: S) {
+        check_len(self.len(), rhs.len());
+        self.iter_mut().zip(rhs).for_each(|(v, w)| v.mut_lerp(w, t));
+    }
+    fn mut_scale_add(&mut self, rhs: &Self, factor: S) {
+        check_len(self.len(), rhs.len());
+        self.iter_mut()
+            .zip(rhs)
+            .for_each(|(v, w)| v.mut_scale_add(w, factor));
+    }
+    fn mut_fma(&mut self, a: &Self, b: &Self) {
+        check_len(self.len(), a.len());
+        check_len(self.len(), b.len());
+        self.iter_mut()
+            .zip(a.iter().zip(b))
+            .for_each(|(v, (a, b))| v.mut_fma(a, b));
+    }
+    fn mut_normalized(&mut self)
+    where
+        Self: ArrayVectorSpaceFloat<S>,
+    {
+        mut_normalized(self)
+    }
+    fn mut_try_normalized(&mut self) -> bool
+    where
+        Self: ArrayVectorSpaceFloat<S>,
+    {
+        mut_try_normalized(self)
+    }
+}
+
+impl<S: Float, V: ArrayVectorSpaceFloatMut<S> + ArrayVectorSpace<S> + Copy>
+    ArrayVectorSpaceFloatMut<S> for Vec<V>
+{
+    fn mut_abs(&mut self) {
+        self.as_mut_slice().mut_abs()
+    }
+    fn mut_recip(&mut self) {
+        self.as_mut_slice().mut_recip()
+    }
+    fn mut_floor(&mut self) {
+        self.as_mut_slice().mut_floor()
+    }
+    fn mut_ceil(&mut self) {
+        self.as_mut_slice().mut_ceil()
+    }
+    fn mut_round(&mut self) {
+        self.as_mut_slice().mut_round()
+    }
+    fn mut_round_ties_even(&mut self) {
+        self.as_mut_slice().mut_round_ties_even()
+    }
+    fn mut_sqrt(&mut self) {
+        self.as_mut_slice().mut_sqrt()
+    }
+    fn mut_exp(&mut self) {
+        self.as_mut_slice().mut_exp()
+    }
+    fn mut_ln(&mut self) {
+        self.as_mut_slice().mut_ln()
+    }
+    fn mut_exp2(&mut self) {
+        self.as_mut_slice().mut_exp2()
+    }
+    fn mut_log2(&mut self) {
+        self.as_mut_slice().mut_log2()
+    }
+    fn mut_sin(&mut self) {
+        self.as_mut_slice().mut_sin()
+    }
+    fn mut_cos(&mut self) {
+        self.as_mut_slice().mut_cos()
+    }
+    fn mut_tan(&mut self) {
+        self.as_mut_slice().mut_tan()
+    }
+    fn mut_asin(&mut self) {
+        self.as_mut_slice().mut_asin()
+    }
+    fn mut_acos(&mut self) {
+        self.as_mut_slice().mut_acos()
+    }
+    fn mut_atan(&mut self) {
+        self.as_mut_slice().mut_atan()
+    }
+    fn mut_atan2(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_atan2(rhs)
+    }
+    fn mut_powi(&mut self, n: i32) {
+        self.as_mut_slice().mut_powi(n)
+    }
+    fn mut_powf(&mut self, rhs: &Self) {
+        self.as_mut_slice().mut_powf(rhs)
+    }
+    fn mut_powf_scalar(&mut self, exp: S) {
+        self.as_mut_slice().mut_powf_scalar(exp)
+    }
+    fn mut_lerp(&mut self, rhs: &Self, t: S) {
+        self.as_mut_slice().mut_lerp(rhs, t)
+    }
+    fn mut_scale_add(&mut self, rhs: &Self, factor: S) {
+        self.as_mut_slice().mut_scale_add(rhs, factor)
+    }
+    fn mut_fma(&mut self, a: &Self, b: &Self) {
+        self.as_mut_slice().mut_fma(a, b)
+    }
+    fn mut_normalized(&mut self)
+    where
+        Self: ArrayVectorSpaceFloat<S>,
+    {
+        mut_normalized(self)
+    }
+    fn mut_try_normalized(&mut self) -> bool
+    where
+        Self: ArrayVectorSpaceFloat<S>,
+    {
+        mut_try_normalized(self)
+    }
+    fn mut_normalize_or_zero(&mut self)
+    where
+        Self: ArrayVectorSpaceFloat<S>,
+    {
+        if !mut_try_normalized(self) {
+            self.iter_mut().for_each(|v| *v = v.map_scalar(|_| S::ZERO));
+        }
+    }
+}
+
+impl<S: Integer, V: ArrayVectorSpaceInteger<S> + ArrayVectorSpaceInit<S> + Copy>
+    ArrayVectorSpaceInteger<S> for Vec<V>
+{
+    fn checked_dot(self, rhs: Self) -> Option<S> {
+        check_len(self.len(), rhs.len());
+        self.into_iter()
+            .zip(rhs)
+            .try_fold(S::ZERO, |a, (v, w)| S::checked_add(a, v.checked_dot(w)?))
+    }
+    fn normalized(mut self) -> Self {
+        self.iter_mut().for_each(|v| *v = v.normalized());
+        self
+    }
+}
+
+impl<S: Scalar, V: ArrayVectorSpacePredicates<S>> ArrayVectorSpacePredicates<S> for Vec<V> {
+    fn all<F: FnMut(S) -> bool>(self, mut f: F) -> bool {
+        self.into_iter().all(|v| v.all(&mut f))
+    }
+    fn any<F: FnMut(S) -> bool>(self, mut f: F) -> bool {
+        self.into_iter().any(|v| v.any(&mut f))
+    }
+}
@@ -0,0 +1,131 @@
+use core::ops::{Add, Deref, DerefMut, Div, Mul, Neg, Sub};
+
+use crate::{ArrayVectorSpace, Scalar};
+
+/// Array wrapper providing the `std::ops` operators, which the orphan rule forbids on `[T; N]`.
+/// `*` and `/` between two vectors are per leaf, and the array traits are reachable through
+/// `Deref`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vector<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    fn from(a: [T; N]) -> Self {
+        Vector(a)
+    }
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    fn from(v: Vector<T, N>) -> Self {
+        v.0
+    }
+}
+
+impl<T, const N: usize> Deref for Vector<T, N> {
+    type Target = [T; N];
+    fn deref(&self) -> &[T; N] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for Vector<T, N> {
+    fn deref_mut(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+}
+
+impl<T: Scalar, const N: usize> Add for Vector<T, N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Vector(self.0.add(rhs.0))
+    }
+}
+
+impl<T: Scalar, const N: usize> Sub for Vector<T, N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Vector(self.0.sub(rhs.0))
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul for Vector<T, N> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Vector(self.0.mul(rhs.0))
+    }
+}
+
+impl<T: Scalar, const N: usize> Div for Vector<T, N> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Vector(self.0.div(rhs.0))
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<T> for Vector<T, N> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        Vector(self.0.scal_mul(rhs))
+    }
+}
+
+impl<T: Scalar, const N: usize> Div<T> for Vector<T, N> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self {
+        Vector(self.0.scal_div(rhs))
+    }
+}
+
+impl<T: Scalar + Neg<Output = T>, const N: usize> Neg for Vector<T, N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Vector(self.0.map(T::neg))
+    }
+}
+
+/// Serialized as a tuple of its `N` elements, so a `Vector<f64, 3>` is a flat sequence of three
+/// scalars and nested wrappers round-trip as nested sequences.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for Vector<T, N> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        use serde::ser::SerializeTuple;
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for x in &self.0 {
+            tuple.serialize_element(x)?;
+        }
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for Vector<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use core::{fmt, marker::PhantomData};
+        use serde::de::{Error, SeqAccess, Visitor};
+
+        struct VectorVisitor<T, const N: usize>(PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const N: usize> Visitor<'de> for VectorVisitor<T, N> {
+            type Value = Vector<T, N>;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a sequence of {N} elements")
+            }
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut err = None;
+                let a: [Option<T>; N] = core::array::from_fn(|i| match err {
+                    Some(_) => None,
+                    None => seq
+                        .next_element()
+                        .and_then(|x| x.ok_or_else(|| Error::invalid_length(i, &self)))
+                        .map_err(|e| err = Some(e))
+                        .ok(),
+                });
+                match err {
+                    Some(e) => Err(e),
+                    None => Ok(Vector(a.map(Option::unwrap))),
+                }
+            }
+        }
+
+        deserializer.deserialize_tuple(N, VectorVisitor(PhantomData))
+    }
+}